@@ -79,11 +79,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     ];
 
     for quality in qualities {
-        println!("\nTesting {} quality...", format!("{:?}", quality));
+        println!("\nTesting {:?} quality...", quality);
 
         match client.track_stream(track.id, quality).await {
             Ok(stream) => {
-                println!("Stream available for {} quality", format!("{:?}", quality));
+                println!("Stream available for {:?} quality", quality);
                 println!("  Codec: {}", stream.codec);
                 println!("  Audio Mode: {}", stream.audio_mode);
                 println!("  URLs: {}", stream.urls.len());
@@ -105,7 +105,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 break; // Use the first available quality
             }
             Err(e) => {
-                println!("{} quality not available: {}", format!("{:?}", quality), e);
+                println!("{:?} quality not available: {}", quality, e);
             }
         }
     }
@@ -151,7 +151,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("DASH playback info retrieved:");
             println!("  Audio Quality: {:?}", dash_info.audio_quality);
             println!("  Bit Depth: {:?} bits", dash_info.bit_depth);
-            println!("  Sample Rate: {} Hz", dash_info.sample_rate);
+            println!("  Sample Rate: {:?} Hz", dash_info.sample_rate);
             println!("  Manifest MIME Type: {}", dash_info.manifest_mime_type);
 
             // Decode the manifest (it's base64 encoded)
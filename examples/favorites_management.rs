@@ -107,7 +107,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             "  {}. {} (added: {})",
             index + 1,
             fav_artist.item.name,
-            "N/A" // Artist favorites don't include creation date in the current API
+            fav_artist.created
         );
     }
 
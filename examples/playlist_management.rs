@@ -7,7 +7,7 @@
 //! - Remove tracks from a playlist
 //! - Get user's playlists
 
-use tidalrs::{Authz, TidalClient};
+use tidalrs::{Authz, TidalClient, TrackId};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -76,7 +76,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Add tracks to the playlist
-    let track_ids: Vec<u64> = search_results
+    let track_ids: Vec<TrackId> = search_results
         .tracks
         .items
         .iter()
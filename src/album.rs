@@ -1,16 +1,27 @@
 use crate::AudioQuality;
+use crate::DEFAULT_STREAM_PAGE_SIZE;
+use crate::Date;
 use crate::Error;
+#[cfg(feature = "extra-fields")]
+use crate::ExtraFields;
 use crate::List;
 use crate::MediaMetadata;
 use crate::Order;
 use crate::OrderDirection;
 use crate::TIDAL_API_BASE_URL;
 use crate::TidalClient;
+use crate::Timestamp;
 use crate::artist::ArtistSummary;
+use crate::id::{AlbumId, ResourceId};
+use crate::markup::{MarkupSegment, parse_markup};
+use crate::paginate;
 use crate::track::Track;
+use crate::video::Video;
+use futures::Stream;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::BTreeMap;
 use strum_macros::AsRefStr;
 use strum_macros::EnumString;
 
@@ -19,7 +30,7 @@ use strum_macros::EnumString;
 /// This enum represents different album formats and categories
 /// that can be used for filtering album searches.
 #[derive(
-    Default, Debug, Serialize, Deserialize, EnumString, AsRefStr, PartialEq, Eq, Copy, Clone,
+    Default, Debug, Serialize, Deserialize, EnumString, AsRefStr, PartialEq, Eq, Hash, Copy, Clone,
 )]
 #[serde(rename_all = "UPPERCASE")]
 #[strum(serialize_all = "UPPERCASE")]
@@ -47,7 +58,7 @@ pub enum AlbumType {
 #[serde(rename_all = "camelCase")]
 pub struct Album {
     /// Unique album identifier
-    pub id: u64,
+    pub id: AlbumId,
     /// List of artists who contributed to this album
     #[serde(default = "Default::default")]
     pub artists: Vec<ArtistSummary>,
@@ -77,9 +88,17 @@ pub struct Album {
     /// Dominant color extracted from the cover art
     pub vibrant_color: Option<String>,
     /// Original release date of the album
-    pub release_date: Option<String>,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::datetime::option_date")
+    )]
+    pub release_date: Option<Date>,
     /// Date when the album became available for streaming
-    pub stream_start_date: Option<String>,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::datetime::option_timestamp")
+    )]
+    pub stream_start_date: Option<Timestamp>,
 
     /// Copyright information
     pub copyright: Option<String>,
@@ -117,6 +136,11 @@ pub struct Album {
 
     /// Available audio modes for this album
     pub audio_modes: Vec<String>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl Album {
@@ -137,6 +161,145 @@ impl Album {
             format!("https://resources.tidal.com/images/{cover_path}/{height}x{width}.jpg")
         })
     }
+
+    /// Generate a URL for the album's animated video cover at the specified dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - Height of the video in pixels
+    /// * `width` - Width of the video in pixels
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(String)` with the full URL of an MP4 file if the album has
+    /// a video cover, or `None` otherwise.
+    pub fn video_cover_url(&self, height: u16, width: u16) -> Option<String> {
+        self.video_cover.as_ref().map(|video_cover| {
+            let video_cover_path = video_cover.replace('-', "/");
+            format!("https://resources.tidal.com/videos/{video_cover_path}/{height}x{width}.mp4")
+        })
+    }
+
+    /// Generate the canonical Tidal share URL for this album.
+    pub fn share_url(&self) -> String {
+        ResourceId::Album(self.id).share_url()
+    }
+}
+
+/// An item on an album, which is either a track or a video.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", content = "item", rename_all = "lowercase")]
+pub enum AlbumItem {
+    /// Audio track
+    Track(Track),
+    /// Video, such as a music video included with the album
+    Video(Video),
+}
+
+/// Editorial review of an album.
+///
+/// The text and summary link to artists and albums with Tidal's inline link
+/// markup, see `text_segments()` and `summary_segments()`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AlbumReview {
+    /// Source of the review (e.g., "TiVo")
+    #[serde(default)]
+    pub source: String,
+    /// When the review was last updated
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::datetime::option_timestamp")
+    )]
+    pub last_updated: Option<Timestamp>,
+    /// The review text, which may contain wimpLink tags
+    pub text: String,
+    /// Summary of the review (may be empty)
+    #[serde(default)]
+    pub summary: String,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl AlbumReview {
+    /// Parse the review text into plain text and links.
+    pub fn text_segments(&self) -> Vec<MarkupSegment> {
+        parse_markup(&self.text)
+    }
+
+    /// Parse the review summary into plain text and links.
+    pub fn summary_segments(&self) -> Vec<MarkupSegment> {
+        parse_markup(&self.summary)
+    }
+}
+
+/// The tracks of an album grouped by disc.
+///
+/// Discs are ordered by number, and the tracks on each disc by track number.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlbumTracklist {
+    /// Discs of the album, which is a single disc for most albums
+    pub discs: Vec<Disc>,
+}
+
+/// A single disc (volume) of an album.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Disc {
+    /// Number of the disc, starting at 1
+    pub number: u32,
+    /// Tracks on the disc in track number order
+    pub tracks: Vec<Track>,
+    /// Total duration of the tracks on the disc in seconds
+    pub duration: u32,
+}
+
+impl AlbumTracklist {
+    /// Group the tracks of an album by disc.
+    ///
+    /// # Arguments
+    ///
+    /// * `tracks` - Tracks of the album in any order, as returned by `album_tracks_all`
+    pub fn new(tracks: Vec<Track>) -> Self {
+        let mut discs: BTreeMap<u32, Vec<Track>> = BTreeMap::new();
+        for track in tracks {
+            discs.entry(track.volume_number).or_default().push(track);
+        }
+
+        let discs = discs
+            .into_iter()
+            .map(|(number, mut tracks)| {
+                tracks.sort_by_key(|track| track.track_number);
+                let duration = tracks.iter().map(|track| track.duration).sum();
+                Disc {
+                    number,
+                    tracks,
+                    duration,
+                }
+            })
+            .collect();
+
+        Self { discs }
+    }
+
+    /// Iterate over every track of the album, disc by disc.
+    pub fn tracks(&self) -> impl Iterator<Item = &Track> {
+        self.discs.iter().flat_map(|disc| &disc.tracks)
+    }
+
+    /// Total duration of the album in seconds.
+    pub fn duration(&self) -> u32 {
+        self.discs.iter().map(|disc| disc.duration).sum()
+    }
+
+    /// Whether the album spans more than one disc.
+    pub fn is_multi_disc(&self) -> bool {
+        self.discs.len() > 1
+    }
 }
 
 /// Represents an album that has been added to a user's favorites.
@@ -147,9 +310,15 @@ impl Album {
 #[serde(rename_all = "camelCase")]
 pub struct FavoriteAlbum {
     /// ISO timestamp when the album was added to favorites
-    pub created: String,
+    #[cfg_attr(feature = "chrono", serde(with = "crate::datetime::timestamp"))]
+    pub created: Timestamp,
     /// The album data
     pub item: Album,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl TidalClient {
@@ -166,10 +335,15 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let album = client.album(123456789).await?;
     /// println!("Album: {} by {}", album.title, album.artists[0].name);
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn album(&self, album_id: u64) -> Result<Album, Error> {
+    pub async fn album(&self, album_id: impl Into<AlbumId>) -> Result<Album, Error> {
+        let album_id: AlbumId = album_id.into();
         let url = format!("{TIDAL_API_BASE_URL}/albums/{album_id}");
 
         let params = serde_json::json!({
@@ -185,6 +359,119 @@ impl TidalClient {
         Ok(resp)
     }
 
+    /// Get several albums by ID.
+    ///
+    /// Albums are requested in chunks of up to 50 IDs, with the chunks fetched
+    /// concurrently, bounded by the client's `get_max_concurrency()` setting.
+    /// An album that isn't available in the client's country gets a
+    /// `ResourceNotFound` error without affecting the rest of its chunk.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_ids` - The unique identifiers of the albums
+    ///
+    /// # Returns
+    ///
+    /// Returns an `(AlbumId, Result<Album, Error>)` pair for every requested ID, in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::AlbumId;
+    ///
+    /// let results = client.albums(&[AlbumId(123456789), AlbumId(987654321)]).await;
+    /// for (album_id, result) in results {
+    ///     match result {
+    ///         Ok(album) => println!("{}: {}", album_id, album.title),
+    ///         Err(e) => println!("{} failed: {}", album_id, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn albums(&self, album_ids: &[AlbumId]) -> Vec<(AlbumId, Result<Album, Error>)> {
+        self.fetch_by_ids(
+            "albums",
+            album_ids,
+            |album_id| self.album(album_id),
+            |album: &Album| album.id,
+            ResourceId::Album,
+        )
+        .await
+    }
+
+    /// Find every album in the catalog with a UPC.
+    ///
+    /// A release can be listed under more than one album ID. A 12-digit UPC and
+    /// the 13-digit EAN made by putting a zero in front of it are treated as the
+    /// same code, and hyphens and spaces are ignored. Only albums available in
+    /// the client's country code are found; use `albums_by_upc_in_countries` to
+    /// look in other countries too.
+    ///
+    /// # Arguments
+    ///
+    /// * `upc` - Universal Product Code (or EAN) of the release
+    ///
+    /// # Returns
+    ///
+    /// Returns every matching album available in the client's country, which is
+    /// empty if there are none there.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let albums = client.albums_by_upc("075596290562").await?;
+    /// for album in albums {
+    ///     println!("{} ({})", album.title, album.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn albums_by_upc(&self, upc: &str) -> Result<Vec<Album>, Error> {
+        let country_code = self.get_country_code();
+        self.albums_by_upc_in_countries(upc, &[&country_code]).await
+    }
+
+    /// Find every album with a UPC in the catalogs of several countries.
+    ///
+    /// Releases are often licensed per region, and Tidal only lists the albums
+    /// available in the country being asked about, so the lookup runs once per
+    /// country. An album found in more than one country is returned once.
+    ///
+    /// # Arguments
+    ///
+    /// * `upc` - Universal Product Code (or EAN) of the release
+    /// * `countries` - ISO 3166-1 alpha-2 country codes to look in (e.g., "US", "GB")
+    ///
+    /// # Returns
+    ///
+    /// Returns every matching album, in the order the countries are given.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let albums = client
+    ///     .albums_by_upc_in_countries("075596290562", &["US", "JP"])
+    ///     .await?;
+    /// println!("{} listings", albums.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn albums_by_upc_in_countries(
+        &self,
+        upc: &str,
+        countries: &[&str],
+    ) -> Result<Vec<Album>, Error> {
+        self.catalog_lookup("albums", "upc", upc, countries, |album: &Album| album.id)
+            .await
+    }
+
     /// Get all tracks for a specific album with pagination support.
     ///
     /// # Arguments
@@ -200,17 +487,22 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let tracks = client.album_tracks(123456789, Some(0), Some(20)).await?;
     /// for track in tracks.items {
     ///     println!("Track: {}", track.title);
     /// }
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn album_tracks(
         &self,
-        album_id: u64,
+        album_id: impl Into<AlbumId>,
         offset: Option<u32>,
         limit: Option<u32>,
     ) -> Result<List<Track>, Error> {
+        let album_id: AlbumId = album_id.into();
         let offset = offset.unwrap_or(0);
         let limit = limit.unwrap_or(100);
 
@@ -230,6 +522,294 @@ impl TidalClient {
         Ok(resp)
     }
 
+    /// Stream all tracks of an album.
+    ///
+    /// Pages through the album's tracks on demand, fetching the next page only
+    /// once the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_id` - The unique identifier of the album
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut tracks = std::pin::pin!(client.album_tracks_stream(123456789));
+    /// while let Some(track) = tracks.try_next().await? {
+    ///     println!("Track: {}", track.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn album_tracks_stream(
+        &self,
+        album_id: impl Into<AlbumId>,
+    ) -> impl Stream<Item = Result<Track, Error>> + '_ {
+        let album_id: AlbumId = album_id.into();
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.album_tracks(album_id, Some(offset), Some(limit))
+        })
+    }
+
+    /// Fetch all tracks of an album.
+    ///
+    /// The first page is fetched to learn the total, after which the remaining
+    /// pages are fetched concurrently, bounded by the client's `max_concurrency`.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_id` - The unique identifier of the album
+    ///
+    /// # Returns
+    ///
+    /// Returns every track of the album, in album order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let tracks = client.album_tracks_all(123456789).await?;
+    /// println!("{} tracks", tracks.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn album_tracks_all(
+        &self,
+        album_id: impl Into<AlbumId>,
+    ) -> Result<Vec<Track>, Error> {
+        let album_id: AlbumId = album_id.into();
+        self.fetch_all(
+            DEFAULT_STREAM_PAGE_SIZE,
+            |offset, limit| self.album_tracks(album_id, Some(offset), Some(limit)),
+            |track| track.id,
+        )
+        .await
+    }
+
+    /// Get the full tracklist of an album, grouped by disc.
+    ///
+    /// All tracks are fetched, however many pages that takes, so this also works
+    /// for box sets with hundreds of tracks.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_id` - The unique identifier of the album
+    ///
+    /// # Returns
+    ///
+    /// Returns the album's discs in order, each with its tracks and duration.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let tracklist = client.album_tracklist(123456789).await?;
+    /// for disc in &tracklist.discs {
+    ///     println!("Disc {} ({}s)", disc.number, disc.duration);
+    ///     for track in &disc.tracks {
+    ///         println!("  {}. {}", track.track_number, track.title);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn album_tracklist(
+        &self,
+        album_id: impl Into<AlbumId>,
+    ) -> Result<AlbumTracklist, Error> {
+        let tracks = self.album_tracks_all(album_id).await?;
+        Ok(AlbumTracklist::new(tracks))
+    }
+
+    /// Get all items on an album, including videos, with pagination support.
+    ///
+    /// Unlike `album_tracks`, this also returns any videos that are part of the album.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_id` - The unique identifier of the album
+    /// * `offset` - Number of items to skip (default: 0)
+    /// * `limit` - Maximum number of items to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of tracks and videos in album order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let items = client.album_items(123456789, None, None).await?;
+    /// for item in items.items {
+    ///     match item {
+    ///         tidalrs::AlbumItem::Track(track) => println!("Track: {}", track.title),
+    ///         tidalrs::AlbumItem::Video(video) => println!("Video: {}", video.title),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn album_items(
+        &self,
+        album_id: impl Into<AlbumId>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<AlbumItem>, Error> {
+        let album_id: AlbumId = album_id.into();
+        let offset = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(100);
+
+        let url = format!("{TIDAL_API_BASE_URL}/albums/{album_id}/items");
+
+        let params = serde_json::json!({
+            "offset": offset,
+            "limit": limit,
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: List<AlbumItem> = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+        Ok(resp)
+    }
+
+    /// Stream all items on an album, including videos.
+    ///
+    /// Pages through the album's items on demand, fetching the next page only
+    /// once the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_id` - The unique identifier of the album
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut items = std::pin::pin!(client.album_items_stream(123456789));
+    /// while let Some(item) = items.try_next().await? {
+    ///     println!("{:?}", item);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn album_items_stream(
+        &self,
+        album_id: impl Into<AlbumId>,
+    ) -> impl Stream<Item = Result<AlbumItem, Error>> + '_ {
+        let album_id: AlbumId = album_id.into();
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.album_items(album_id, Some(offset), Some(limit))
+        })
+    }
+
+    /// Get the editorial review of an album.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_id` - The unique identifier of the album
+    ///
+    /// # Returns
+    ///
+    /// Returns the review with its source. Use `text_segments()` to get the
+    /// text with its links parsed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::MarkupSegment;
+    ///
+    /// let review = client.album_review(123456789).await?;
+    /// for segment in review.text_segments() {
+    ///     match segment {
+    ///         MarkupSegment::Text(text) => print!("{text}"),
+    ///         MarkupSegment::Link { target, text } => print!("{text} <{}>", target.share_url()),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn album_review(&self, album_id: impl Into<AlbumId>) -> Result<AlbumReview, Error> {
+        let album_id: AlbumId = album_id.into();
+        let url = format!("{TIDAL_API_BASE_URL}/albums/{album_id}/review");
+        let params = serde_json::json!({
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: AlbumReview = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+
+    /// Get albums similar to an album with pagination support.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_id` - The unique identifier of the album
+    /// * `offset` - Number of albums to skip (default: 0)
+    /// * `limit` - Maximum number of albums to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of similar albums.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let similar = client.album_similar(123456789, None, Some(10)).await?;
+    /// for album in similar.items {
+    ///     println!("Similar album: {}", album.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn album_similar(
+        &self,
+        album_id: impl Into<AlbumId>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<Album>, Error> {
+        let album_id: AlbumId = album_id.into();
+        let offset = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(100);
+
+        let url = format!("{TIDAL_API_BASE_URL}/albums/{album_id}/similar");
+
+        let params = serde_json::json!({
+            "offset": offset,
+            "limit": limit,
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: List<Album> = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+
     /// Get the authenticated user's favorite albums with pagination and sorting.
     ///
     /// # Arguments
@@ -246,10 +826,14 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let favorites = client.favorite_albums(None, Some(10), None, None).await?;
     /// for favorite in favorites.items {
     ///     println!("Favorite: {}", favorite.item.title);
     /// }
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn favorite_albums(
         &self,
@@ -283,6 +867,77 @@ impl TidalClient {
         Ok(resp)
     }
 
+    /// Stream all of the authenticated user's favorite albums.
+    ///
+    /// Pages through all of the user's favorite albums on demand, fetching
+    /// the next page only once the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Sort order (default: Date)
+    /// * `order_direction` - Sort direction (default: Desc)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut favorites = std::pin::pin!(client.favorite_albums_stream(None, None));
+    /// while let Some(favorite) = favorites.try_next().await? {
+    ///     println!("Favorite: {}", favorite.item.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn favorite_albums_stream(
+        &self,
+        order: Option<Order>,
+        order_direction: Option<OrderDirection>,
+    ) -> impl Stream<Item = Result<FavoriteAlbum, Error>> + '_ {
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.favorite_albums(Some(offset), Some(limit), order, order_direction)
+        })
+    }
+
+    /// Fetch all of the authenticated user's favorite albums.
+    ///
+    /// The first page is fetched to learn the total, after which the remaining
+    /// pages are fetched concurrently, bounded by the client's `max_concurrency`.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Sort order (default: Date)
+    /// * `order_direction` - Sort direction (default: Desc)
+    ///
+    /// # Returns
+    ///
+    /// Returns every album the user has favorited, in the requested order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let favorites = client.favorite_albums_all(None, None).await?;
+    /// println!("{} favorite albums", favorites.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn favorite_albums_all(
+        &self,
+        order: Option<Order>,
+        order_direction: Option<OrderDirection>,
+    ) -> Result<Vec<FavoriteAlbum>, Error> {
+        self.fetch_all(
+            DEFAULT_STREAM_PAGE_SIZE,
+            |offset, limit| self.favorite_albums(Some(offset), Some(limit), order, order_direction),
+            |favorite| favorite.item.id,
+        )
+        .await
+    }
+
     /// Add an album to the authenticated user's favorites.
     ///
     /// # Arguments
@@ -292,10 +947,15 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// client.add_favorite_album(123456789).await?;
     /// println!("Album added to favorites!");
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn add_favorite_album(&self, album_id: u64) -> Result<(), Error> {
+    pub async fn add_favorite_album(&self, album_id: impl Into<AlbumId>) -> Result<(), Error> {
+        let album_id: AlbumId = album_id.into();
         let user_id = self
             .get_user_id()
             .ok_or(Error::UserAuthenticationRequired)?;
@@ -312,6 +972,8 @@ impl TidalClient {
             .do_request(Method::POST, &url, Some(params), None)
             .await?;
 
+        self.update_cached_favorite(ResourceId::Album(album_id), true);
+
         Ok(())
     }
 
@@ -324,10 +986,15 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// client.remove_favorite_album(123456789).await?;
     /// println!("Album removed from favorites!");
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn remove_favorite_album(&self, album_id: u64) -> Result<(), Error> {
+    pub async fn remove_favorite_album(&self, album_id: impl Into<AlbumId>) -> Result<(), Error> {
+        let album_id: AlbumId = album_id.into();
         let user_id = self
             .get_user_id()
             .ok_or(Error::UserAuthenticationRequired)?;
@@ -343,6 +1010,94 @@ impl TidalClient {
             .do_request(Method::DELETE, &url, Some(params), None)
             .await?;
 
+        self.update_cached_favorite(ResourceId::Album(album_id), false);
+
         Ok(())
     }
+
+    /// Add several albums to the authenticated user's favorites.
+    ///
+    /// The albums are sent in batches of comma-separated ids, so a large library
+    /// takes a request per batch rather than one per album. When Tidal rejects a
+    /// batch because of its IDs, its albums are retried one at a time, so a single
+    /// bad ID doesn't fail the rest of its batch. Any other error, such as a rate
+    /// limit or a server error, fails every album of the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_ids` - The unique identifiers of the albums to favorite
+    ///
+    /// # Returns
+    ///
+    /// Returns a `(AlbumId, Result<(), Error>)` pair for every requested ID, in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::AlbumId;
+    ///
+    /// let results = client.add_favorite_albums(&[AlbumId(123456789), AlbumId(987654321)]).await;
+    /// for (album_id, result) in results {
+    ///     if let Err(e) = result {
+    ///         println!("{} failed: {}", album_id, e);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_favorite_albums(
+        &self,
+        album_ids: &[AlbumId],
+    ) -> Vec<(AlbumId, Result<(), Error>)> {
+        self.update_favorites(
+            Method::POST,
+            "albums",
+            "albumIds",
+            album_ids,
+            ResourceId::Album,
+        )
+        .await
+    }
+
+    /// Remove several albums from the authenticated user's favorites.
+    ///
+    /// Like `add_favorite_albums`, the albums are sent in batches and a batch
+    /// rejected because of its IDs is retried one album at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_ids` - The unique identifiers of the albums to remove from favorites
+    ///
+    /// # Returns
+    ///
+    /// Returns a `(AlbumId, Result<(), Error>)` pair for every requested ID, in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::AlbumId;
+    ///
+    /// let results = client.remove_favorite_albums(&[AlbumId(123456789), AlbumId(987654321)]).await;
+    /// let removed = results.iter().filter(|(_, result)| result.is_ok()).count();
+    /// println!("Removed {removed} albums");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_favorite_albums(
+        &self,
+        album_ids: &[AlbumId],
+    ) -> Vec<(AlbumId, Result<(), Error>)> {
+        self.update_favorites(
+            Method::DELETE,
+            "albums",
+            "albumIds",
+            album_ids,
+            ResourceId::Album,
+        )
+        .await
+    }
 }
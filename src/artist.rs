@@ -1,15 +1,27 @@
+use crate::DEFAULT_STREAM_PAGE_SIZE;
 use crate::Error;
+#[cfg(feature = "extra-fields")]
+use crate::ExtraFields;
 use crate::List;
 use crate::Order;
 use crate::OrderDirection;
 use crate::TIDAL_API_BASE_URL;
 use crate::TidalClient;
+use crate::Timestamp;
 use crate::album::{Album, AlbumType};
 use crate::deserialize_null_default;
+use crate::id::{ArtistId, MixId, ResourceId, UserId};
+use crate::markup::{MarkupSegment, parse_markup};
+use crate::mix::ARTIST_MIX;
+use crate::paginate;
+use crate::track::Track;
+use futures::Stream;
 use reqwest::Method;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 
 /// Represents an artist from the Tidal catalog.
 ///
@@ -19,7 +31,7 @@ use std::collections::HashMap;
 #[serde(rename_all = "camelCase")]
 pub struct Artist {
     /// Unique artist identifier
-    pub id: u64,
+    pub id: ArtistId,
     /// Artist name
     pub name: String,
 
@@ -35,7 +47,7 @@ pub struct Artist {
     pub url: String,
 
     /// The Tidal user ID of the artist, will be None or zero if the artist is not a known user
-    pub user_id: Option<u64>,
+    pub user_id: Option<UserId>,
 
     /// Popularity score for the artist
     #[serde(default)]
@@ -55,13 +67,26 @@ pub struct Artist {
 
     /// Mix playlists associated with the artist
     #[serde(default, deserialize_with = "deserialize_null_default")]
-    pub mixes: HashMap<String, String>,
+    pub mixes: HashMap<String, MixId>,
 
     /// Whether the artist is currently being spotlighted by Tidal
+    #[serde(default)]
     pub spotlighted: bool,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl Artist {
+    /// Get the id of the artist's radio mix, if Tidal has one for this artist.
+    ///
+    /// Pass it to `mix_items` to get the tracks and videos of the radio.
+    pub fn radio_mix_id(&self) -> Option<&MixId> {
+        self.mixes.get(ARTIST_MIX)
+    }
+
     /// Generate a URL for the artist's profile picture at the specified dimensions.
     ///
     /// If no artist picture is available, falls back to the selected album cover.
@@ -94,12 +119,23 @@ impl Artist {
             },
         }
     }
+
+    /// Generate the canonical Tidal share URL for this artist.
+    pub fn share_url(&self) -> String {
+        ResourceId::Artist(self.id).share_url()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
 pub struct FavoriteArtist {
-    pub created: String,
+    #[cfg_attr(feature = "chrono", serde(with = "crate::datetime::timestamp"))]
+    pub created: Timestamp,
     pub item: Artist,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// Represents a role or category that an artist has in the music industry.
@@ -112,6 +148,11 @@ pub struct ArtistRole {
     pub category: String,
     /// Unique identifier for the role category
     pub category_id: i64,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// Biography information for an artist.
@@ -124,12 +165,47 @@ pub struct ArtistBio {
     /// Source of the biography (e.g., "TiVo")
     pub source: String,
     /// ISO timestamp when the biography was last updated
-    pub last_updated: String,
+    #[cfg_attr(feature = "chrono", serde(with = "crate::datetime::timestamp"))]
+    pub last_updated: Timestamp,
     /// The biography text, which may contain HTML and wimpLink tags
     pub text: String,
     /// Summary of the biography (may be empty)
     #[serde(default)]
     pub summary: String,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl ArtistBio {
+    /// Parse the biography text into plain text and links.
+    ///
+    /// Use `render_plain_text`, `render_markdown` or `render_html` to display it.
+    pub fn text_segments(&self) -> Vec<MarkupSegment> {
+        parse_markup(&self.text)
+    }
+
+    /// Parse the biography summary into plain text and links.
+    pub fn summary_segments(&self) -> Vec<MarkupSegment> {
+        parse_markup(&self.summary)
+    }
+}
+
+/// An external link for an artist, such as their homepage or a social profile.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtistLink {
+    /// Full URL of the link
+    pub url: String,
+    /// Name of the linked site (e.g., "OFFICIAL_HOMEPAGE", "TWITTER", "WIKIPEDIA")
+    pub site_name: String,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// A simplified representation of an artist used in lists and summaries.
@@ -140,7 +216,7 @@ pub struct ArtistBio {
 #[serde(rename_all = "camelCase")]
 pub struct ArtistSummary {
     /// Unique artist identifier
-    pub id: u64,
+    pub id: ArtistId,
     /// Artist name
     pub name: String,
     /// Artist profile picture identifier
@@ -160,6 +236,11 @@ pub struct ArtistSummary {
     #[serde(rename = "type")]
     #[serde(default)]
     pub artist_type: Option<String>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl ArtistSummary {
@@ -183,6 +264,144 @@ impl ArtistSummary {
             format!("https://resources.tidal.com/images/{picture_path}/{height}x{width}.jpg")
         })
     }
+
+    /// Generate the canonical Tidal share URL for this artist.
+    pub fn share_url(&self) -> String {
+        ResourceId::Artist(self.id).share_url()
+    }
+}
+
+/// An artist's complete discography, grouped the way Tidal groups it on artist pages.
+///
+/// Each group is sorted by release date, oldest first, with undated releases last.
+/// Re-issues are removed, see `Discography::new`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Discography {
+    /// Studio and live albums
+    pub albums: Vec<Album>,
+    /// EPs and singles
+    pub eps_and_singles: Vec<Album>,
+    /// Compilations credited to the artist, such as best-ofs
+    pub compilations: Vec<Album>,
+    /// Releases by other artists that the artist appears on
+    pub appears_on: Vec<Album>,
+}
+
+impl Discography {
+    /// Build a discography from the albums returned by each `artist_albums` filter.
+    ///
+    /// Tidal lists both the artist's own compilations and releases they merely appear
+    /// on under `AlbumType::Compilations`; these are split by whether the artist is
+    /// credited on the release.
+    ///
+    /// Re-issues are detected by a UPC shared with a release in any group, or,
+    /// within a group, by the same title and version ignoring case, release type
+    /// and number of tracks. Only the earliest release is kept, so an album's
+    /// title-track single is kept alongside the album.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The artist the albums were fetched for
+    /// * `albums` - Albums returned without a filter
+    /// * `eps_and_singles` - Albums returned for `AlbumType::EpsAndSingles`
+    /// * `compilations` - Albums returned for `AlbumType::Compilations`
+    pub fn new(
+        artist_id: ArtistId,
+        albums: Vec<Album>,
+        eps_and_singles: Vec<Album>,
+        compilations: Vec<Album>,
+    ) -> Self {
+        let (compilations, appears_on) = compilations
+            .into_iter()
+            .partition(|album| album.artists.iter().any(|artist| artist.id == artist_id));
+
+        let mut seen = ReleaseKeys::default();
+        Self {
+            albums: seen.dedupe(albums),
+            eps_and_singles: seen.dedupe(eps_and_singles),
+            compilations: seen.dedupe(compilations),
+            appears_on: seen.dedupe(appears_on),
+        }
+    }
+
+    /// Iterate over every release in the discography, group by group.
+    pub fn iter(&self) -> impl Iterator<Item = &Album> {
+        self.albums
+            .iter()
+            .chain(&self.eps_and_singles)
+            .chain(&self.compilations)
+            .chain(&self.appears_on)
+    }
+
+    /// Total number of releases in the discography.
+    pub fn len(&self) -> usize {
+        self.albums.len()
+            + self.eps_and_singles.len()
+            + self.compilations.len()
+            + self.appears_on.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// UPCs of the releases already placed in a discography
+#[derive(Default)]
+struct ReleaseKeys {
+    upcs: HashSet<String>,
+}
+
+impl ReleaseKeys {
+    // Sort a group by release date and drop its re-issues. A UPC is the same release in
+    // any group, while a matching title only counts within the group, since a single
+    // usually shares its title with the album it's taken from.
+    fn dedupe(&mut self, mut albums: Vec<Album>) -> Vec<Album> {
+        albums.sort_by(compare_release_dates);
+
+        let mut titles = HashSet::new();
+        albums.retain(|album| {
+            let upc = album
+                .upc
+                .as_deref()
+                .map(str::trim)
+                .filter(|upc| !upc.is_empty());
+            if upc.is_some_and(|upc| self.upcs.contains(upc)) || !titles.insert(title_key(album)) {
+                return false;
+            }
+
+            if let Some(upc) = upc {
+                self.upcs.insert(upc.to_string());
+            }
+            true
+        });
+        albums
+    }
+}
+
+// What makes two releases with different UPCs the same release
+fn title_key(album: &Album) -> (String, String, AlbumType, u32) {
+    (
+        album.title.trim().to_lowercase(),
+        album
+            .version
+            .as_deref()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase(),
+        album.album_type,
+        album.number_of_tracks,
+    )
+}
+
+// Oldest first, undated releases last, then by title
+fn compare_release_dates(a: &Album, b: &Album) -> Ordering {
+    (a.release_date.is_none(), &a.release_date, &a.title).cmp(&(
+        b.release_date.is_none(),
+        &b.release_date,
+        &b.title,
+    ))
 }
 
 impl TidalClient {
@@ -199,10 +418,15 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let artist = client.artist(123456789).await?;
     /// println!("Artist: {}", artist.name);
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn artist(&self, artist_id: u64) -> Result<Artist, Error> {
+    pub async fn artist(&self, artist_id: impl Into<ArtistId>) -> Result<Artist, Error> {
+        let artist_id: ArtistId = artist_id.into();
         let url = format!("{TIDAL_API_BASE_URL}/artists/{artist_id}");
         let params = serde_json::json!({
             "countryCode": self.get_country_code(),
@@ -215,6 +439,49 @@ impl TidalClient {
         Ok(resp)
     }
 
+    /// Get several artists by ID.
+    ///
+    /// Artists are requested in chunks of up to 50 IDs, with the chunks fetched
+    /// concurrently, bounded by the client's `get_max_concurrency()` setting.
+    /// If Tidal rejects a chunk because of an unknown ID, its artists are
+    /// fetched one at a time, so only the unknown ID fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_ids` - The unique identifiers of the artists
+    ///
+    /// # Returns
+    ///
+    /// Returns an `(ArtistId, Result<Artist, Error>)` pair for every requested ID, in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::ArtistId;
+    ///
+    /// let results = client.artists(&[ArtistId(123456789), ArtistId(987654321)]).await;
+    /// for (artist_id, result) in results {
+    ///     match result {
+    ///         Ok(artist) => println!("{}: {}", artist_id, artist.name),
+    ///         Err(e) => println!("{} failed: {}", artist_id, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn artists(&self, artist_ids: &[ArtistId]) -> Vec<(ArtistId, Result<Artist, Error>)> {
+        self.fetch_by_ids(
+            "artists",
+            artist_ids,
+            |artist_id| self.artist(artist_id),
+            |artist: &Artist| artist.id,
+            ResourceId::Artist,
+        )
+        .await
+    }
+
     /// Get biography information for a specific artist.
     ///
     /// # Arguments
@@ -229,15 +496,20 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let bio = client.artist_bio(123456789, Some(true)).await?;
     /// println!("Bio source: {}", bio.source);
     /// println!("Bio text: {}", bio.text);
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn artist_bio(
         &self,
-        artist_id: u64,
+        artist_id: impl Into<ArtistId>,
         include_image_links: Option<bool>,
     ) -> Result<ArtistBio, Error> {
+        let artist_id: ArtistId = artist_id.into();
         let url = format!("{TIDAL_API_BASE_URL}/artists/{artist_id}/bio");
         let include_image_links = include_image_links.unwrap_or(true);
         let params = serde_json::json!({
@@ -252,6 +524,273 @@ impl TidalClient {
         Ok(resp)
     }
 
+    /// Get an artist's most popular tracks with pagination support.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist
+    /// * `offset` - Number of tracks to skip (default: 0)
+    /// * `limit` - Maximum number of tracks to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of the artist's tracks, most popular first.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let top_tracks = client.artist_top_tracks(123456789, None, Some(10)).await?;
+    /// for track in top_tracks.items {
+    ///     println!("Top track: {}", track.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn artist_top_tracks(
+        &self,
+        artist_id: impl Into<ArtistId>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<Track>, Error> {
+        let artist_id: ArtistId = artist_id.into();
+        self.artist_list("toptracks", artist_id, offset, limit)
+            .await
+    }
+
+    /// Stream all of an artist's top tracks.
+    ///
+    /// Pages through the tracks on demand, fetching the next page only once
+    /// the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut tracks = std::pin::pin!(client.artist_top_tracks_stream(123456789));
+    /// while let Some(track) = tracks.try_next().await? {
+    ///     println!("Top track: {}", track.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn artist_top_tracks_stream(
+        &self,
+        artist_id: impl Into<ArtistId>,
+    ) -> impl Stream<Item = Result<Track, Error>> + '_ {
+        let artist_id: ArtistId = artist_id.into();
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.artist_top_tracks(artist_id, Some(offset), Some(limit))
+        })
+    }
+
+    /// Get artists similar to an artist with pagination support.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist
+    /// * `offset` - Number of artists to skip (default: 0)
+    /// * `limit` - Maximum number of artists to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of similar artists, most similar first.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let similar = client.artist_similar(123456789, None, Some(10)).await?;
+    /// for artist in similar.items {
+    ///     println!("Similar artist: {}", artist.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn artist_similar(
+        &self,
+        artist_id: impl Into<ArtistId>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<Artist>, Error> {
+        let artist_id: ArtistId = artist_id.into();
+        self.artist_list("similar", artist_id, offset, limit).await
+    }
+
+    /// Stream all artists similar to an artist.
+    ///
+    /// Pages through the similar artists on demand, fetching the next page only
+    /// once the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut similar = std::pin::pin!(client.artist_similar_stream(123456789));
+    /// while let Some(artist) = similar.try_next().await? {
+    ///     println!("Similar artist: {}", artist.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn artist_similar_stream(
+        &self,
+        artist_id: impl Into<ArtistId>,
+    ) -> impl Stream<Item = Result<Artist, Error>> + '_ {
+        let artist_id: ArtistId = artist_id.into();
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.artist_similar(artist_id, Some(offset), Some(limit))
+        })
+    }
+
+    /// Get the tracks of an artist's radio with pagination support.
+    ///
+    /// The radio mixes the artist's tracks with tracks by similar artists.
+    /// For the radio as a `Mix`, use `Artist::radio_mix_id` with `mix`.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist
+    /// * `offset` - Number of tracks to skip (default: 0)
+    /// * `limit` - Maximum number of tracks to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of tracks in the artist's radio.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let radio = client.artist_radio(123456789, None, Some(50)).await?;
+    /// for track in radio.items {
+    ///     println!("Radio: {} - {}", track.artists[0].name, track.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn artist_radio(
+        &self,
+        artist_id: impl Into<ArtistId>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<Track>, Error> {
+        let artist_id: ArtistId = artist_id.into();
+        self.artist_list("radio", artist_id, offset, limit).await
+    }
+
+    /// Stream all tracks of an artist's radio.
+    ///
+    /// Pages through the radio on demand, fetching the next page only once
+    /// the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut radio = std::pin::pin!(client.artist_radio_stream(123456789));
+    /// while let Some(track) = radio.try_next().await? {
+    ///     println!("Radio: {}", track.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn artist_radio_stream(
+        &self,
+        artist_id: impl Into<ArtistId>,
+    ) -> impl Stream<Item = Result<Track, Error>> + '_ {
+        let artist_id: ArtistId = artist_id.into();
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.artist_radio(artist_id, Some(offset), Some(limit))
+        })
+    }
+
+    /// Get an artist's external links, such as their homepage and social profiles.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist
+    /// * `offset` - Number of links to skip (default: 0)
+    /// * `limit` - Maximum number of links to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of the artist's links.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let links = client.artist_links(123456789, None, None).await?;
+    /// for link in links.items {
+    ///     println!("{}: {}", link.site_name, link.url);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn artist_links(
+        &self,
+        artist_id: impl Into<ArtistId>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<ArtistLink>, Error> {
+        let artist_id: ArtistId = artist_id.into();
+        self.artist_list("links", artist_id, offset, limit).await
+    }
+
+    // Fetch a page of one of the artist sub-collections at /artists/{id}/{path}
+    async fn artist_list<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        artist_id: ArtistId,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<T>, Error> {
+        let offset = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(100);
+
+        let url = format!("{TIDAL_API_BASE_URL}/artists/{artist_id}/{path}");
+
+        let params = serde_json::json!({
+            "offset": offset,
+            "limit": limit,
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: List<T> = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+
     /// Get the authenticated user's favorite artists with pagination and sorting.
     ///
     /// # Arguments
@@ -268,10 +807,14 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let favorites = client.favorite_artists(None, Some(10), None, None).await?;
-    /// for artist in favorites.items {
-    ///     println!("Favorite: {}", artist.name);
+    /// for favorite in favorites.items {
+    ///     println!("Favorite: {}", favorite.item.name);
     /// }
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn favorite_artists(
         &self,
@@ -305,6 +848,79 @@ impl TidalClient {
         Ok(resp)
     }
 
+    /// Stream all of the authenticated user's favorite artists.
+    ///
+    /// Pages through all of the user's favorite artists on demand, fetching
+    /// the next page only once the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Sort order (default: Date)
+    /// * `order_direction` - Sort direction (default: Desc)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut favorites = std::pin::pin!(client.favorite_artists_stream(None, None));
+    /// while let Some(favorite) = favorites.try_next().await? {
+    ///     println!("Favorite: {}", favorite.item.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn favorite_artists_stream(
+        &self,
+        order: Option<Order>,
+        order_direction: Option<OrderDirection>,
+    ) -> impl Stream<Item = Result<FavoriteArtist, Error>> + '_ {
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.favorite_artists(Some(offset), Some(limit), order, order_direction)
+        })
+    }
+
+    /// Fetch all of the authenticated user's favorite artists.
+    ///
+    /// The first page is fetched to learn the total, after which the remaining
+    /// pages are fetched concurrently, bounded by the client's `max_concurrency`.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Sort order (default: Date)
+    /// * `order_direction` - Sort direction (default: Desc)
+    ///
+    /// # Returns
+    ///
+    /// Returns every artist the user has favorited, in the requested order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let favorites = client.favorite_artists_all(None, None).await?;
+    /// println!("{} favorite artists", favorites.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn favorite_artists_all(
+        &self,
+        order: Option<Order>,
+        order_direction: Option<OrderDirection>,
+    ) -> Result<Vec<FavoriteArtist>, Error> {
+        self.fetch_all(
+            DEFAULT_STREAM_PAGE_SIZE,
+            |offset, limit| {
+                self.favorite_artists(Some(offset), Some(limit), order, order_direction)
+            },
+            |favorite| favorite.item.id,
+        )
+        .await
+    }
+
     /// Get all albums for a specific artist with pagination and filtering.
     ///
     /// # Arguments
@@ -321,18 +937,23 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let albums = client.artist_albums(123456789, None, Some(20), None).await?;
     /// for album in albums.items {
     ///     println!("Album: {}", album.title);
     /// }
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn artist_albums(
         &self,
-        artist_id: u64,
+        artist_id: impl Into<ArtistId>,
         album_type: Option<AlbumType>,
         offset: Option<u32>,
         limit: Option<u32>,
     ) -> Result<List<Album>, Error> {
+        let artist_id: ArtistId = artist_id.into();
         let offset = offset.unwrap_or(0);
         let limit = limit.unwrap_or(100);
 
@@ -357,6 +978,125 @@ impl TidalClient {
         Ok(resp)
     }
 
+    /// Stream all albums of an artist.
+    ///
+    /// Pages through the artist's albums on demand, fetching the next page only
+    /// once the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist
+    /// * `album_type` - Filter by album type (optional)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut albums = std::pin::pin!(client.artist_albums_stream(123456789, None));
+    /// while let Some(album) = albums.try_next().await? {
+    ///     println!("Album: {}", album.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn artist_albums_stream(
+        &self,
+        artist_id: impl Into<ArtistId>,
+        album_type: Option<AlbumType>,
+    ) -> impl Stream<Item = Result<Album, Error>> + '_ {
+        let artist_id: ArtistId = artist_id.into();
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.artist_albums(artist_id, album_type, Some(offset), Some(limit))
+        })
+    }
+
+    /// Fetch all albums of an artist.
+    ///
+    /// The first page is fetched to learn the total, after which the remaining
+    /// pages are fetched concurrently, bounded by the client's `max_concurrency`.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist
+    /// * `album_type` - Filter by album type (optional)
+    ///
+    /// # Returns
+    ///
+    /// Returns every album by the artist matching the filter.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let albums = client.artist_albums_all(123456789, None).await?;
+    /// println!("{} albums", albums.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn artist_albums_all(
+        &self,
+        artist_id: impl Into<ArtistId>,
+        album_type: Option<AlbumType>,
+    ) -> Result<Vec<Album>, Error> {
+        let artist_id: ArtistId = artist_id.into();
+        self.fetch_all(
+            DEFAULT_STREAM_PAGE_SIZE,
+            |offset, limit| self.artist_albums(artist_id, album_type, Some(offset), Some(limit)),
+            |album| album.id,
+        )
+        .await
+    }
+
+    /// Fetch an artist's complete discography.
+    ///
+    /// Every `artist_albums` category is fetched in full, then grouped, sorted by
+    /// release date and stripped of re-issues as described in `Discography::new`.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist
+    ///
+    /// # Returns
+    ///
+    /// Returns the artist's albums, EPs and singles, compilations, and the
+    /// releases they appear on.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let discography = client.artist_discography(123456789).await?;
+    /// for album in &discography.albums {
+    ///     println!("{:?} {}", album.release_date, album.title);
+    /// }
+    /// println!("{} releases in total", discography.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn artist_discography(
+        &self,
+        artist_id: impl Into<ArtistId>,
+    ) -> Result<Discography, Error> {
+        let artist_id: ArtistId = artist_id.into();
+        let (albums, eps_and_singles, compilations) = futures::try_join!(
+            self.artist_albums_all(artist_id, None),
+            self.artist_albums_all(artist_id, Some(AlbumType::EpsAndSingles)),
+            self.artist_albums_all(artist_id, Some(AlbumType::Compilations)),
+        )?;
+
+        Ok(Discography::new(
+            artist_id,
+            albums,
+            eps_and_singles,
+            compilations,
+        ))
+    }
+
     /// Add an artist to the authenticated user's favorites.
     ///
     /// # Arguments
@@ -366,10 +1106,15 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// client.add_favorite_artist(123456789).await?;
     /// println!("Artist added to favorites!");
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn add_favorite_artist(&self, artist_id: u64) -> Result<(), Error> {
+    pub async fn add_favorite_artist(&self, artist_id: impl Into<ArtistId>) -> Result<(), Error> {
+        let artist_id: ArtistId = artist_id.into();
         let user_id = self
             .get_user_id()
             .ok_or(Error::UserAuthenticationRequired)?;
@@ -386,6 +1131,8 @@ impl TidalClient {
             .do_request(Method::POST, &url, Some(params), None)
             .await?;
 
+        self.update_cached_favorite(ResourceId::Artist(artist_id), true);
+
         Ok(())
     }
 
@@ -398,10 +1145,18 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// client.remove_favorite_artist(123456789).await?;
     /// println!("Artist removed from favorites!");
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn remove_favorite_artist(&self, artist_id: u64) -> Result<(), Error> {
+    pub async fn remove_favorite_artist(
+        &self,
+        artist_id: impl Into<ArtistId>,
+    ) -> Result<(), Error> {
+        let artist_id: ArtistId = artist_id.into();
         let user_id = self
             .get_user_id()
             .ok_or(Error::UserAuthenticationRequired)?;
@@ -417,6 +1172,94 @@ impl TidalClient {
             .do_request(Method::DELETE, &url, Some(params), None)
             .await?;
 
+        self.update_cached_favorite(ResourceId::Artist(artist_id), false);
+
         Ok(())
     }
+
+    /// Add several artists to the authenticated user's favorites.
+    ///
+    /// The artists are sent in batches of comma-separated ids, so a large library
+    /// takes a request per batch rather than one per artist. When Tidal rejects a
+    /// batch because of its IDs, its artists are retried one at a time, so a single
+    /// bad ID doesn't fail the rest of its batch. Any other error, such as a rate
+    /// limit or a server error, fails every artist of the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_ids` - The unique identifiers of the artists to favorite
+    ///
+    /// # Returns
+    ///
+    /// Returns a `(ArtistId, Result<(), Error>)` pair for every requested ID, in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::ArtistId;
+    ///
+    /// let results = client.add_favorite_artists(&[ArtistId(123456789), ArtistId(987654321)]).await;
+    /// for (artist_id, result) in results {
+    ///     if let Err(e) = result {
+    ///         println!("{} failed: {}", artist_id, e);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_favorite_artists(
+        &self,
+        artist_ids: &[ArtistId],
+    ) -> Vec<(ArtistId, Result<(), Error>)> {
+        self.update_favorites(
+            Method::POST,
+            "artists",
+            "artistIds",
+            artist_ids,
+            ResourceId::Artist,
+        )
+        .await
+    }
+
+    /// Remove several artists from the authenticated user's favorites.
+    ///
+    /// Like `add_favorite_artists`, the artists are sent in batches and a batch
+    /// rejected because of its IDs is retried one artist at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_ids` - The unique identifiers of the artists to remove from favorites
+    ///
+    /// # Returns
+    ///
+    /// Returns a `(ArtistId, Result<(), Error>)` pair for every requested ID, in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::ArtistId;
+    ///
+    /// let results = client.remove_favorite_artists(&[ArtistId(123456789), ArtistId(987654321)]).await;
+    /// let removed = results.iter().filter(|(_, result)| result.is_ok()).count();
+    /// println!("Removed {removed} artists");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_favorite_artists(
+        &self,
+        artist_ids: &[ArtistId],
+    ) -> Vec<(ArtistId, Result<(), Error>)> {
+        self.update_favorites(
+            Method::DELETE,
+            "artists",
+            "artistIds",
+            artist_ids,
+            ResourceId::Artist,
+        )
+        .await
+    }
 }
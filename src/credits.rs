@@ -0,0 +1,370 @@
+use crate::Error;
+#[cfg(feature = "extra-fields")]
+use crate::ExtraFields;
+use crate::List;
+use crate::TIDAL_API_BASE_URL;
+use crate::TidalClient;
+use crate::album::AlbumItem;
+use crate::artist::ArtistRole;
+use crate::id::{AlbumId, ArtistId, TrackId};
+use reqwest::Method;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use strum_macros::{EnumString, IntoStaticStr};
+
+/// The role a contributor had in making a track or album.
+///
+/// Tidal sends roles as free text. Common roles are parsed into their own
+/// variants, ignoring case, and anything else is kept as `Other`.
+#[derive(Debug, EnumString, IntoStaticStr, PartialEq, Eq, Hash, Clone)]
+#[strum(ascii_case_insensitive)]
+pub enum ContributorRole {
+    /// Credited main artist
+    #[strum(to_string = "Main Artist", serialize = "Artist")]
+    MainArtist,
+    /// Credited featured artist
+    #[strum(to_string = "Featured Artist")]
+    FeaturedArtist,
+    /// Producer
+    #[strum(to_string = "Producer")]
+    Producer,
+    /// Co-producer or additional producer
+    #[strum(to_string = "Co-Producer", serialize = "Additional Producer")]
+    CoProducer,
+    /// Executive producer
+    #[strum(to_string = "Executive Producer")]
+    ExecutiveProducer,
+    /// Composer of the music
+    #[strum(to_string = "Composer")]
+    Composer,
+    /// Writer of the lyrics
+    #[strum(to_string = "Lyricist")]
+    Lyricist,
+    /// Songwriter, credited for both music and lyrics
+    #[strum(to_string = "Songwriter", serialize = "Writer")]
+    Songwriter,
+    /// Arranger
+    #[strum(to_string = "Arranger")]
+    Arranger,
+    /// Engineer, including recording engineers
+    #[strum(to_string = "Engineer", serialize = "Recording Engineer")]
+    Engineer,
+    /// Mixing engineer
+    #[strum(to_string = "Mixing Engineer", serialize = "Mixer")]
+    MixingEngineer,
+    /// Mastering engineer
+    #[strum(to_string = "Mastering Engineer")]
+    MasteringEngineer,
+    /// Remixer
+    #[strum(to_string = "Remixer")]
+    Remixer,
+    /// Programmer of drums, synths or other electronics
+    #[strum(to_string = "Programmer", serialize = "Programming")]
+    Programmer,
+    /// Vocalist, including background vocals
+    #[strum(
+        to_string = "Vocals",
+        serialize = "Vocal",
+        serialize = "Background Vocals"
+    )]
+    Vocals,
+    /// Instrumental or other performer
+    #[strum(to_string = "Performer")]
+    Performer,
+    /// Conductor
+    #[strum(to_string = "Conductor")]
+    Conductor,
+    /// Record label
+    #[strum(to_string = "Record Label", serialize = "Label")]
+    RecordLabel,
+    /// Music publisher
+    #[strum(to_string = "Publisher")]
+    Publisher,
+    /// Any role not listed above, as sent by Tidal
+    #[strum(default)]
+    Other(String),
+}
+
+impl AsRef<str> for ContributorRole {
+    fn as_ref(&self) -> &str {
+        match self {
+            ContributorRole::Other(role) => role,
+            role => role.into(),
+        }
+    }
+}
+
+impl Serialize for ContributorRole {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_ref())
+    }
+}
+
+impl<'de> Deserialize<'de> for ContributorRole {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let role = String::deserialize(deserializer)?;
+        Ok(role.parse().unwrap_or(ContributorRole::Other(role)))
+    }
+}
+
+impl ArtistRole {
+    /// Parse the role's category into a `ContributorRole`.
+    pub fn role(&self) -> ContributorRole {
+        self.category
+            .parse()
+            .unwrap_or_else(|_| ContributorRole::Other(self.category.clone()))
+    }
+}
+
+/// A person or organisation credited on a track or album.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Contributor {
+    /// Name of the contributor
+    pub name: String,
+    /// Tidal artist id of the contributor, if they have an artist page
+    #[serde(default)]
+    pub id: Option<ArtistId>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Everyone credited with one role on a track.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Credit {
+    /// The role being credited
+    #[serde(rename = "type")]
+    pub role: ContributorRole,
+    /// Contributors credited with the role
+    #[serde(default)]
+    pub contributors: Vec<Contributor>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A single contributor to a track along with their role.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackContributor {
+    /// Name of the contributor
+    pub name: String,
+    /// Role of the contributor on the track
+    pub role: ContributorRole,
+    /// Tidal artist id of the contributor, if they have an artist page
+    #[serde(default)]
+    pub id: Option<ArtistId>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A track or video on an album together with its credits.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AlbumItemWithCredits {
+    /// The track or video
+    #[serde(flatten)]
+    pub item: AlbumItem,
+    /// Credits for the item, one entry per role
+    #[serde(default)]
+    pub credits: Vec<Credit>,
+}
+
+impl AlbumItemWithCredits {
+    /// Get the contributors credited with a role on this item.
+    pub fn contributors<'a>(
+        &'a self,
+        role: &'a ContributorRole,
+    ) -> impl Iterator<Item = &'a Contributor> {
+        contributors_with_role(&self.credits, role)
+    }
+}
+
+/// Get the contributors credited with a role in a list of credits.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// # let client = tidalrs::TidalClient::new("client_id".to_string());
+/// use tidalrs::ContributorRole;
+///
+/// let credits = client.track_credits(123456789).await?;
+/// for producer in tidalrs::contributors_with_role(&credits, &ContributorRole::Producer) {
+///     println!("Produced by {}", producer.name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn contributors_with_role<'a>(
+    credits: &'a [Credit],
+    role: &'a ContributorRole,
+) -> impl Iterator<Item = &'a Contributor> {
+    credits
+        .iter()
+        .filter(move |credit| &credit.role == role)
+        .flat_map(|credit| &credit.contributors)
+}
+
+impl TidalClient {
+    /// Get the credits of a track, grouped by role.
+    ///
+    /// # Arguments
+    ///
+    /// * `track_id` - The unique identifier of the track
+    ///
+    /// # Returns
+    ///
+    /// Returns one `Credit` per role, each listing the contributors credited with it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let credits = client.track_credits(123456789).await?;
+    /// for credit in credits {
+    ///     let names: Vec<_> = credit.contributors.iter().map(|c| c.name.as_str()).collect();
+    ///     println!("{}: {}", credit.role.as_ref(), names.join(", "));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn track_credits(&self, track_id: impl Into<TrackId>) -> Result<Vec<Credit>, Error> {
+        let track_id: TrackId = track_id.into();
+        let url = format!("{TIDAL_API_BASE_URL}/tracks/{track_id}/credits");
+        let params = serde_json::json!({
+            "includeContributors": true,
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: Vec<Credit> = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+
+    /// Get the contributors to a track with pagination support.
+    ///
+    /// Unlike `track_credits`, every contributor is a separate entry with their role.
+    ///
+    /// # Arguments
+    ///
+    /// * `track_id` - The unique identifier of the track
+    /// * `offset` - Number of contributors to skip (default: 0)
+    /// * `limit` - Maximum number of contributors to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of the track's contributors.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let contributors = client.track_contributors(123456789, None, None).await?;
+    /// for contributor in contributors.items {
+    ///     println!("{} ({})", contributor.name, contributor.role.as_ref());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn track_contributors(
+        &self,
+        track_id: impl Into<TrackId>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<TrackContributor>, Error> {
+        let track_id: TrackId = track_id.into();
+        let offset = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(100);
+
+        let url = format!("{TIDAL_API_BASE_URL}/tracks/{track_id}/contributors");
+
+        let params = serde_json::json!({
+            "offset": offset,
+            "limit": limit,
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: List<TrackContributor> = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+
+    /// Get the tracks and videos on an album together with their credits.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_id` - The unique identifier of the album
+    /// * `offset` - Number of items to skip (default: 0)
+    /// * `limit` - Maximum number of items to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of album items, each with its credits.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::{AlbumItem, ContributorRole};
+    ///
+    /// let items = client.album_items_with_credits(123456789, None, None).await?;
+    /// for item in &items.items {
+    ///     if let AlbumItem::Track(track) = &item.item {
+    ///         let composers: Vec<_> = item
+    ///             .contributors(&ContributorRole::Composer)
+    ///             .map(|c| c.name.as_str())
+    ///             .collect();
+    ///         println!("{} - composed by {}", track.title, composers.join(", "));
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn album_items_with_credits(
+        &self,
+        album_id: impl Into<AlbumId>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<AlbumItemWithCredits>, Error> {
+        let album_id: AlbumId = album_id.into();
+        let offset = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(100);
+
+        let url = format!("{TIDAL_API_BASE_URL}/albums/{album_id}/items/credits");
+
+        let params = serde_json::json!({
+            "offset": offset,
+            "limit": limit,
+            "replace": true,
+            "includeContributors": true,
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: List<AlbumItemWithCredits> = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+}
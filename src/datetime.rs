@@ -0,0 +1,179 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Deserializer, Serializer};
+
+// The format Tidal uses for most timestamps, e.g. 2014-09-30T00:00:00.000+0000
+const TIDAL_TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.3f%z";
+
+// Epoch values above this are taken to be in milliseconds rather than seconds
+const EPOCH_MILLIS_THRESHOLD: i64 = 100_000_000_000;
+
+// Tidal sends dates as strings in a handful of formats, and occasionally as epoch numbers
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawDateTime {
+    Text(String),
+    Epoch(i64),
+}
+
+/// Parse a timestamp in any of the formats Tidal is known to return.
+///
+/// Accepted formats are Tidal's own `2014-09-30T00:00:00.000+0000`, RFC 3339,
+/// timestamps with a `GMT` suffix or without an offset (taken as UTC), plain dates
+/// (taken as midnight UTC) and epoch seconds or milliseconds.
+///
+/// # Example
+///
+/// ```
+/// let created = tidalrs::parse_timestamp("2014-09-30T00:00:00.000+0000").unwrap();
+/// assert_eq!(created.to_rfc3339(), "2014-09-30T00:00:00+00:00");
+/// ```
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+
+    if let Some(datetime) = parse_date_and_time(value) {
+        return Some(datetime);
+    }
+    if let Some(date) = parse_date(value) {
+        return date.and_hms_opt(0, 0, 0).map(|datetime| datetime.and_utc());
+    }
+
+    value.parse().ok().and_then(from_epoch)
+}
+
+/// Parse a calendar date in any of the formats Tidal is known to return.
+///
+/// Accepted formats are `2014-09-30`, a bare year such as `2014` (taken as
+/// January 1st), and any full timestamp accepted by `parse_timestamp`.
+///
+/// # Example
+///
+/// ```
+/// let released = tidalrs::parse_date("2014-09-30").unwrap();
+/// assert_eq!(released.to_string(), "2014-09-30");
+/// ```
+pub fn parse_date(value: &str) -> Option<NaiveDate> {
+    let value = value.trim();
+
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Some(date);
+    }
+    if value.len() == 4
+        && let Ok(year) = value.parse()
+    {
+        return NaiveDate::from_ymd_opt(year, 1, 1);
+    }
+
+    // Some endpoints send release dates as full timestamps
+    parse_date_and_time(value).map(|datetime| datetime.date_naive())
+}
+
+fn parse_date_and_time(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(datetime) = DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z") {
+        return Some(datetime.with_timezone(&Utc));
+    }
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Some(datetime.with_timezone(&Utc));
+    }
+
+    // Artist bios use a GMT suffix, e.g. 2019-04-08T15:34:29.013GMT
+    let value = value.trim_end_matches("GMT").trim_end_matches("UTC");
+    NaiveDateTime::parse_from_str(value.trim_end(), "%Y-%m-%dT%H:%M:%S%.f")
+        .ok()
+        .map(|datetime| datetime.and_utc())
+}
+
+fn from_epoch(epoch: i64) -> Option<DateTime<Utc>> {
+    if epoch.abs() >= EPOCH_MILLIS_THRESHOLD {
+        Utc.timestamp_millis_opt(epoch).single()
+    } else {
+        Utc.timestamp_opt(epoch, 0).single()
+    }
+}
+
+fn timestamp_from_raw<E: serde::de::Error>(raw: RawDateTime) -> Result<DateTime<Utc>, E> {
+    match raw {
+        RawDateTime::Text(text) => {
+            parse_timestamp(&text).ok_or_else(|| E::custom(format!("invalid timestamp: {text}")))
+        }
+        RawDateTime::Epoch(epoch) => {
+            from_epoch(epoch).ok_or_else(|| E::custom(format!("invalid timestamp: {epoch}")))
+        }
+    }
+}
+
+fn date_from_raw<E: serde::de::Error>(raw: RawDateTime) -> Result<NaiveDate, E> {
+    match raw {
+        RawDateTime::Text(text) => {
+            parse_date(&text).ok_or_else(|| E::custom(format!("invalid date: {text}")))
+        }
+        RawDateTime::Epoch(_) => timestamp_from_raw(raw).map(|datetime| datetime.date_naive()),
+    }
+}
+
+// Blank strings in optional fields are treated as missing
+fn is_blank(raw: &RawDateTime) -> bool {
+    matches!(raw, RawDateTime::Text(text) if text.trim().is_empty())
+}
+
+pub(crate) mod timestamp {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        value: &DateTime<Utc>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&value.format(TIDAL_TIMESTAMP_FORMAT))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Utc>, D::Error> {
+        timestamp_from_raw(RawDateTime::deserialize(deserializer)?)
+    }
+}
+
+pub(crate) mod option_timestamp {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<DateTime<Utc>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => super::timestamp::serialize(value, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<DateTime<Utc>>, D::Error> {
+        match Option::<RawDateTime>::deserialize(deserializer)? {
+            Some(raw) if !is_blank(&raw) => timestamp_from_raw(raw).map(Some),
+            _ => Ok(None),
+        }
+    }
+}
+
+pub(crate) mod option_date {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<NaiveDate>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.collect_str(&value.format("%Y-%m-%d")),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<NaiveDate>, D::Error> {
+        match Option::<RawDateTime>::deserialize(deserializer)? {
+            Some(raw) if !is_blank(&raw) => date_from_raw(raw).map(Some),
+            _ => Ok(None),
+        }
+    }
+}
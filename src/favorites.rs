@@ -0,0 +1,466 @@
+use crate::Error;
+use crate::TIDAL_API_BASE_URL;
+use crate::TidalClient;
+use crate::id::{AlbumId, ArtistId, PlaylistId, ResourceId, TrackId, UserId, VideoId};
+use crate::is_id_error;
+use crate::share_error;
+use reqwest::Method;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::hash::Hash;
+use std::str::FromStr;
+use std::sync::MutexGuard;
+
+/// The ids of everything the authenticated user has favorited.
+///
+/// Use `contains` to check whether a track, album, artist, video or playlist
+/// is a favorite without paging through the favorites themselves.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct FavoriteIds {
+    /// Favorite tracks
+    #[serde(rename = "TRACK", default, deserialize_with = "deserialize_ids")]
+    pub tracks: HashSet<TrackId>,
+    /// Favorite albums
+    #[serde(rename = "ALBUM", default, deserialize_with = "deserialize_ids")]
+    pub albums: HashSet<AlbumId>,
+    /// Favorite artists
+    #[serde(rename = "ARTIST", default, deserialize_with = "deserialize_ids")]
+    pub artists: HashSet<ArtistId>,
+    /// Favorite videos
+    #[serde(rename = "VIDEO", default, deserialize_with = "deserialize_ids")]
+    pub videos: HashSet<VideoId>,
+    /// Favorite playlists
+    #[serde(rename = "PLAYLIST", default, deserialize_with = "deserialize_ids")]
+    pub playlists: HashSet<PlaylistId>,
+}
+
+impl FavoriteIds {
+    /// Check whether a resource is among the favorites.
+    ///
+    /// User profiles can't be favorited, so they're never contained.
+    pub fn contains(&self, resource: &ResourceId) -> bool {
+        match resource {
+            ResourceId::Track(id) => self.tracks.contains(id),
+            ResourceId::Album(id) => self.albums.contains(id),
+            ResourceId::Artist(id) => self.artists.contains(id),
+            ResourceId::Video(id) => self.videos.contains(id),
+            ResourceId::Playlist(id) => self.playlists.contains(id),
+            ResourceId::UserProfile(_) => false,
+        }
+    }
+
+    /// Add a resource to the favorites.
+    ///
+    /// Returns whether the resource was newly added.
+    pub fn insert(&mut self, resource: ResourceId) -> bool {
+        match resource {
+            ResourceId::Track(id) => self.tracks.insert(id),
+            ResourceId::Album(id) => self.albums.insert(id),
+            ResourceId::Artist(id) => self.artists.insert(id),
+            ResourceId::Video(id) => self.videos.insert(id),
+            ResourceId::Playlist(id) => self.playlists.insert(id),
+            ResourceId::UserProfile(_) => false,
+        }
+    }
+
+    /// Remove a resource from the favorites.
+    ///
+    /// Returns whether the resource was a favorite.
+    pub fn remove(&mut self, resource: &ResourceId) -> bool {
+        match resource {
+            ResourceId::Track(id) => self.tracks.remove(id),
+            ResourceId::Album(id) => self.albums.remove(id),
+            ResourceId::Artist(id) => self.artists.remove(id),
+            ResourceId::Video(id) => self.videos.remove(id),
+            ResourceId::Playlist(id) => self.playlists.remove(id),
+            ResourceId::UserProfile(_) => false,
+        }
+    }
+
+    /// Get the total number of favorites.
+    pub fn len(&self) -> usize {
+        self.tracks.len()
+            + self.albums.len()
+            + self.artists.len()
+            + self.videos.len()
+            + self.playlists.len()
+    }
+
+    /// Check whether there are no favorites at all.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// Tidal sends every id as a string, including the numeric ones
+fn deserialize_ids<'de, D, T>(deserializer: D) -> Result<HashSet<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + Eq + Hash,
+    T::Err: std::fmt::Display,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawId {
+        String(String),
+        Number(u64),
+    }
+
+    let ids = Option::<Vec<RawId>>::deserialize(deserializer)?.unwrap_or_default();
+    ids.into_iter()
+        .map(|id| match id {
+            RawId::String(id) => id.parse().map_err(serde::de::Error::custom),
+            RawId::Number(id) => id.to_string().parse().map_err(serde::de::Error::custom),
+        })
+        .collect()
+}
+
+impl TidalClient {
+    /// Get the ids of everything the authenticated user has favorited.
+    ///
+    /// The ids are fetched in a single request and cached on the client, where
+    /// `is_favorite` reads them.
+    ///
+    /// # Returns
+    ///
+    /// Returns the ids of the user's favorite tracks, albums, artists, videos
+    /// and playlists.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let favorites = client.favorite_ids().await?;
+    /// println!("{} favorite tracks", favorites.tracks.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn favorite_ids(&self) -> Result<FavoriteIds, Error> {
+        let user_id = self
+            .get_user_id()
+            .ok_or(Error::UserAuthenticationRequired)?;
+        let url = format!("{TIDAL_API_BASE_URL}/users/{user_id}/favorites/ids");
+
+        let params = serde_json::json!({
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: FavoriteIds = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        *self.lock_favorite_ids() = Some((user_id, resp.clone()));
+
+        Ok(resp)
+    }
+
+    /// Check whether a track, album, artist, video or playlist is one of the
+    /// authenticated user's favorites.
+    ///
+    /// The first call fetches the favorite ids with `favorite_ids`, and later
+    /// calls answer from the cache. The cache is kept up to date by the
+    /// `add_favorite_*` and `remove_favorite_*` methods of this client, but not
+    /// by changes made elsewhere, such as in another Tidal app. Call
+    /// `favorite_ids` or `clear_favorite_ids_cache` to pick those up.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource` - The id of the resource to check
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::{ResourceId, TrackId};
+    ///
+    /// if client.is_favorite(&ResourceId::Track(TrackId(123456789))).await? {
+    ///     println!("♥");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_favorite(&self, resource: &ResourceId) -> Result<bool, Error> {
+        let user_id = self
+            .get_user_id()
+            .ok_or(Error::UserAuthenticationRequired)?;
+
+        let cached = self
+            .lock_favorite_ids()
+            .as_ref()
+            .filter(|(cached_user_id, _)| *cached_user_id == user_id)
+            .map(|(_, ids)| ids.contains(resource));
+        if let Some(favorite) = cached {
+            return Ok(favorite);
+        }
+
+        Ok(self.favorite_ids().await?.contains(resource))
+    }
+
+    /// Drop the cached favorite ids, so the next `is_favorite` call fetches
+    /// them again.
+    pub fn clear_favorite_ids_cache(&self) {
+        *self.lock_favorite_ids() = None;
+    }
+
+    // Record a favorite being added or removed, if the favorite ids are cached
+    pub(crate) fn update_cached_favorite(&self, resource: ResourceId, favorite: bool) {
+        let user_id = self.get_user_id();
+        let mut cache = self.lock_favorite_ids();
+        let Some((cached_user_id, ids)) = cache.as_mut() else {
+            return;
+        };
+        if Some(*cached_user_id) != user_id {
+            return;
+        }
+
+        if favorite {
+            ids.insert(resource);
+        } else {
+            ids.remove(&resource);
+        }
+    }
+
+    fn lock_favorite_ids(&self) -> MutexGuard<'_, Option<(UserId, FavoriteIds)>> {
+        self.favorite_ids
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+// Maximum number of ids added or removed with a single request
+const FAVORITES_BATCH_SIZE: usize = 50;
+
+impl TidalClient {
+    // Add (with POST) or remove (with DELETE) favorites in batches of comma-separated
+    // ids, giving every id its own result, in the same order as the input.
+    pub(crate) async fn update_favorites<I>(
+        &self,
+        method: Method,
+        kind: &str,
+        param: &str,
+        ids: &[I],
+        resource: fn(I) -> ResourceId,
+    ) -> Vec<(I, Result<(), Error>)>
+    where
+        I: Display + Clone,
+    {
+        let Some(user_id) = self.get_user_id() else {
+            return ids
+                .iter()
+                .map(|id| (id.clone(), Err(Error::UserAuthenticationRequired)))
+                .collect();
+        };
+        let add = method == Method::POST;
+
+        let results = self
+            .send_favorite_batches(ids, |ids| {
+                self.favorites_request(method.clone(), user_id, kind, param, ids)
+            })
+            .await;
+
+        for (id, result) in &results {
+            if result.is_ok() {
+                self.update_cached_favorite(resource(id.clone()), add);
+            }
+        }
+
+        results
+    }
+
+    // Send the ids in batches with `request`, which takes comma-separated ids.
+    //
+    // A batch that Tidal rejects because of its ids is retried one id at a time, so only
+    // the bad ids fail. Any other error, such as a rate limit, an expired session or a
+    // server error, is given to every id of the batch rather than repeated per id.
+    async fn send_favorite_batches<I, F, Fut>(
+        &self,
+        ids: &[I],
+        request: F,
+    ) -> Vec<(I, Result<(), Error>)>
+    where
+        I: Display + Clone,
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = Result<(), Error>>,
+    {
+        let mut results = Vec::with_capacity(ids.len());
+        for batch in ids.chunks(FAVORITES_BATCH_SIZE) {
+            let joined = batch
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+
+            let batch_results = match request(joined).await {
+                Ok(()) => batch.iter().map(|id| (id.clone(), Ok(()))).collect(),
+                Err(err) if batch.len() > 1 && is_id_error(&err) => {
+                    self.fetch_concurrently(batch, |id| request(id.to_string()))
+                        .await
+                }
+                Err(err) => share_error(batch, err),
+            };
+            results.extend(batch_results);
+        }
+
+        results
+    }
+
+    // Send a single add or remove request for comma-separated ids
+    async fn favorites_request(
+        &self,
+        method: Method,
+        user_id: UserId,
+        kind: &str,
+        param: &str,
+        ids: String,
+    ) -> Result<(), Error> {
+        let mut params = serde_json::json!({
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let url = if method == Method::DELETE {
+            format!("{TIDAL_API_BASE_URL}/users/{user_id}/favorites/{kind}/{ids}")
+        } else {
+            params[param] = Value::String(ids);
+            format!("{TIDAL_API_BASE_URL}/users/{user_id}/favorites/{kind}")
+        };
+
+        let _: Value = self.do_request(method, &url, Some(params), None).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TidalApiError;
+    use std::cell::RefCell;
+    use std::future::{Ready, ready};
+
+    fn api_error(status: u16) -> Error {
+        Error::TidalApiError(TidalApiError {
+            status,
+            sub_status: 0,
+            user_message: String::new(),
+        })
+    }
+
+    // A request that records the ids it was sent, failing with `status` when they include `bad_id`
+    fn request<'a>(
+        sent: &'a RefCell<Vec<String>>,
+        bad_id: u64,
+        status: u16,
+    ) -> impl Fn(String) -> Ready<Result<(), Error>> + 'a {
+        move |ids| {
+            let failed = ids.split(',').any(|id| id == bad_id.to_string());
+            sent.borrow_mut().push(ids);
+            ready(if failed {
+                Err(api_error(status))
+            } else {
+                Ok(())
+            })
+        }
+    }
+
+    // The status of the API error a result failed with, looking inside shared batch errors
+    fn status(result: &Result<(), Error>) -> Option<u16> {
+        let err = match result {
+            Ok(()) => return None,
+            Err(Error::BatchFailed(err)) => &**err,
+            Err(err) => err,
+        };
+        match err {
+            Error::TidalApiError(err) => Some(err.status),
+            _ => None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ids_are_sent_in_batches() {
+        let client = TidalClient::new("client_id".to_string());
+        let ids: Vec<TrackId> = (1..=120).map(TrackId).collect();
+        let sent = RefCell::new(Vec::new());
+
+        let results = client
+            .send_favorite_batches(&ids, request(&sent, 0, 400))
+            .await;
+
+        let batch_sizes: Vec<usize> = sent
+            .borrow()
+            .iter()
+            .map(|ids| ids.split(',').count())
+            .collect();
+        assert_eq!(batch_sizes, [50, 50, 20]);
+        assert_eq!(results.len(), 120);
+        assert!(
+            results
+                .iter()
+                .zip(&ids)
+                .all(|((id, result), expected)| id == expected && result.is_ok())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejected_ids_are_retried_one_at_a_time() {
+        let client = TidalClient::new("client_id".to_string());
+        let ids: Vec<TrackId> = (1..=60).map(TrackId).collect();
+
+        for rejected in [400, 404] {
+            let sent = RefCell::new(Vec::new());
+            let results = client
+                .send_favorite_batches(&ids, request(&sent, 7, rejected))
+                .await;
+
+            // The first batch, then each of its ids, then the second batch
+            assert_eq!(sent.borrow().len(), 1 + 50 + 1);
+            let failed: Vec<_> = results
+                .iter()
+                .filter(|(_, result)| result.is_err())
+                .collect();
+            assert_eq!(failed.len(), 1);
+            assert_eq!(failed[0].0, TrackId(7));
+            assert_eq!(status(&failed[0].1), Some(rejected));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_other_errors_fail_the_whole_batch() {
+        let client = TidalClient::new("client_id".to_string());
+        let ids: Vec<TrackId> = (1..=60).map(TrackId).collect();
+
+        for status_code in [401, 429, 500, 503] {
+            let sent = RefCell::new(Vec::new());
+            let results = client
+                .send_favorite_batches(&ids, request(&sent, 7, status_code))
+                .await;
+
+            // No per-id retries
+            assert_eq!(sent.borrow().len(), 2);
+            assert!(results[..50].iter().all(|(_, result)| {
+                matches!(result, Err(Error::BatchFailed(_))) && status(result) == Some(status_code)
+            }));
+            assert!(results[50..].iter().all(|(_, result)| result.is_ok()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_single_id_batch_keeps_its_error() {
+        let client = TidalClient::new("client_id".to_string());
+        let sent = RefCell::new(Vec::new());
+
+        let results = client
+            .send_favorite_batches(&[TrackId(7)], request(&sent, 7, 404))
+            .await;
+
+        assert_eq!(sent.borrow().len(), 1);
+        assert!(matches!(results[0].1, Err(Error::TidalApiError(_))));
+    }
+}
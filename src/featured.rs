@@ -0,0 +1,283 @@
+use crate::DEFAULT_STREAM_PAGE_SIZE;
+use crate::Error;
+use crate::List;
+use crate::TidalClient;
+use crate::album::Album;
+use crate::paginate;
+use crate::playlist::Playlist;
+use crate::track::Track;
+use futures::Stream;
+
+impl TidalClient {
+    /// Get newly released albums with pagination support.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Number of albums to skip (default: 0)
+    /// * `limit` - Maximum number of albums to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of newly released albums, newest first.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let albums = client.new_albums(None, Some(20)).await?;
+    /// for album in albums.items {
+    ///     println!("{}", album.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn new_albums(
+        &self,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<Album>, Error> {
+        self.list_at("featured/new/albums", offset, limit).await
+    }
+
+    /// Stream all newly released albums.
+    ///
+    /// Pages through the albums on demand, fetching the next page only once the
+    /// previous one has been consumed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::{StreamExt, TryStreamExt};
+    ///
+    /// let albums: Vec<_> = client.new_albums_stream().take(50).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_albums_stream(&self) -> impl Stream<Item = Result<Album, Error>> + '_ {
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.new_albums(Some(offset), Some(limit))
+        })
+    }
+
+    /// Get newly released tracks with pagination support.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Number of tracks to skip (default: 0)
+    /// * `limit` - Maximum number of tracks to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of newly released tracks, newest first.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let tracks = client.new_tracks(None, Some(20)).await?;
+    /// for track in tracks.items {
+    ///     println!("{}", track.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn new_tracks(
+        &self,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<Track>, Error> {
+        self.list_at("featured/new/tracks", offset, limit).await
+    }
+
+    /// Stream all newly released tracks.
+    ///
+    /// Pages through the tracks on demand, fetching the next page only once the
+    /// previous one has been consumed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::{StreamExt, TryStreamExt};
+    ///
+    /// let tracks: Vec<_> = client.new_tracks_stream().take(50).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_tracks_stream(&self) -> impl Stream<Item = Result<Track, Error>> + '_ {
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.new_tracks(Some(offset), Some(limit))
+        })
+    }
+
+    /// Get the most played albums in the client's country with pagination support.
+    ///
+    /// The chart follows the client's country code, so use `set_country_code`
+    /// or `with_country_code` to get the chart of another country.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Number of albums to skip (default: 0)
+    /// * `limit` - Maximum number of albums to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of albums on the country's chart, most played first.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let albums = client.top_albums(None, Some(20)).await?;
+    /// for album in albums.items {
+    ///     println!("{}", album.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn top_albums(
+        &self,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<Album>, Error> {
+        self.list_at("featured/top/albums", offset, limit).await
+    }
+
+    /// Stream the album chart of the client's country.
+    ///
+    /// Pages through the albums on demand, fetching the next page only once the
+    /// previous one has been consumed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::{StreamExt, TryStreamExt};
+    ///
+    /// let albums: Vec<_> = client.top_albums_stream().take(50).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn top_albums_stream(&self) -> impl Stream<Item = Result<Album, Error>> + '_ {
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.top_albums(Some(offset), Some(limit))
+        })
+    }
+
+    /// Get the most played tracks in the client's country with pagination support.
+    ///
+    /// The chart follows the client's country code, so use `set_country_code`
+    /// or `with_country_code` to get the chart of another country.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Number of tracks to skip (default: 0)
+    /// * `limit` - Maximum number of tracks to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of tracks on the country's chart, most played first.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let tracks = client.top_tracks(None, Some(20)).await?;
+    /// for track in tracks.items {
+    ///     println!("{}", track.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn top_tracks(
+        &self,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<Track>, Error> {
+        self.list_at("featured/top/tracks", offset, limit).await
+    }
+
+    /// Stream the track chart of the client's country.
+    ///
+    /// Pages through the tracks on demand, fetching the next page only once the
+    /// previous one has been consumed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::{StreamExt, TryStreamExt};
+    ///
+    /// let tracks: Vec<_> = client.top_tracks_stream().take(50).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn top_tracks_stream(&self) -> impl Stream<Item = Result<Track, Error>> + '_ {
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.top_tracks(Some(offset), Some(limit))
+        })
+    }
+
+    /// Get playlists picked by Tidal's editors with pagination support.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Number of playlists to skip (default: 0)
+    /// * `limit` - Maximum number of playlists to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of editorial playlists.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let playlists = client.editorial_playlists(None, Some(20)).await?;
+    /// for playlist in playlists.items {
+    ///     println!("{}", playlist.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn editorial_playlists(
+        &self,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<Playlist>, Error> {
+        self.list_at("featured/recommended/playlists", offset, limit)
+            .await
+    }
+
+    /// Stream all playlists picked by Tidal's editors.
+    ///
+    /// Pages through the playlists on demand, fetching the next page only once the
+    /// previous one has been consumed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::{StreamExt, TryStreamExt};
+    ///
+    /// let playlists: Vec<_> = client.editorial_playlists_stream().take(50).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn editorial_playlists_stream(&self) -> impl Stream<Item = Result<Playlist, Error>> + '_ {
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.editorial_playlists(Some(offset), Some(limit))
+        })
+    }
+}
@@ -0,0 +1,293 @@
+use crate::DEFAULT_STREAM_PAGE_SIZE;
+use crate::Error;
+#[cfg(feature = "extra-fields")]
+use crate::ExtraFields;
+use crate::List;
+use crate::TIDAL_API_BASE_URL;
+use crate::TidalClient;
+use crate::album::Album;
+use crate::paginate;
+use crate::playlist::Playlist;
+use crate::track::Track;
+use futures::Stream;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+/// A genre of the Tidal catalog, such as "Pop" or "Hip-Hop".
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Genre {
+    /// Display name of the genre
+    pub name: String,
+    /// Path of the genre, used to browse it (e.g., "Pop" or "Hiphop")
+    pub path: String,
+    /// Whether the genre has playlists
+    #[serde(default)]
+    pub has_playlists: bool,
+    /// Whether the genre has artists
+    #[serde(default)]
+    pub has_artists: bool,
+    /// Whether the genre has albums
+    #[serde(default)]
+    pub has_albums: bool,
+    /// Whether the genre has tracks
+    #[serde(default)]
+    pub has_tracks: bool,
+    /// Whether the genre has videos
+    #[serde(default)]
+    pub has_videos: bool,
+    /// Genre image identifier
+    ///
+    /// Use image_url() to get the full URL of the image
+    pub image: Option<String>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl Genre {
+    /// Generate a URL for the genre's image at the specified dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - Height of the image in pixels
+    /// * `width` - Width of the image in pixels
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(String)` with the full URL if an image is available,
+    /// or `None` if no image is set.
+    pub fn image_url(&self, height: u16, width: u16) -> Option<String> {
+        self.image.as_ref().map(|image| {
+            let image_path = image.replace('-', "/");
+            format!("https://resources.tidal.com/images/{image_path}/{height}x{width}.jpg")
+        })
+    }
+}
+
+impl TidalClient {
+    /// Get every genre of the catalog.
+    ///
+    /// # Returns
+    ///
+    /// Returns the genres with their paths and images, which can be passed to
+    /// `genre_albums`, `genre_tracks` and `genre_playlists`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let genres = client.genres().await?;
+    /// for genre in genres.iter().filter(|genre| genre.has_tracks) {
+    ///     println!("{} ({})", genre.name, genre.path);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn genres(&self) -> Result<Vec<Genre>, Error> {
+        let url = format!("{TIDAL_API_BASE_URL}/genres");
+        let params = serde_json::json!({
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: Vec<Genre> = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+
+    /// Get the albums of a genre with pagination support.
+    ///
+    /// # Arguments
+    ///
+    /// * `genre` - Path of the genre, from `Genre::path`
+    /// * `offset` - Number of albums to skip (default: 0)
+    /// * `limit` - Maximum number of albums to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of the genre's albums.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let albums = client.genre_albums("Jazz", None, Some(20)).await?;
+    /// for album in albums.items {
+    ///     println!("{}", album.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn genre_albums(
+        &self,
+        genre: &str,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<Album>, Error> {
+        self.list_at(&format!("genres/{genre}/albums"), offset, limit)
+            .await
+    }
+
+    /// Stream all albums of a genre.
+    ///
+    /// Pages through the genre's albums on demand, fetching the next page only
+    /// once the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `genre` - Path of the genre, from `Genre::path`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let albums: Vec<_> = client.genre_albums_stream("Jazz").try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn genre_albums_stream<'a>(
+        &'a self,
+        genre: &'a str,
+    ) -> impl Stream<Item = Result<Album, Error>> + 'a {
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.genre_albums(genre, Some(offset), Some(limit))
+        })
+    }
+
+    /// Get the tracks of a genre with pagination support.
+    ///
+    /// # Arguments
+    ///
+    /// * `genre` - Path of the genre, from `Genre::path`
+    /// * `offset` - Number of tracks to skip (default: 0)
+    /// * `limit` - Maximum number of tracks to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of the genre's tracks.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let tracks = client.genre_tracks("Jazz", None, Some(50)).await?;
+    /// for track in tracks.items {
+    ///     println!("{}", track.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn genre_tracks(
+        &self,
+        genre: &str,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<Track>, Error> {
+        self.list_at(&format!("genres/{genre}/tracks"), offset, limit)
+            .await
+    }
+
+    /// Stream all tracks of a genre.
+    ///
+    /// Pages through the genre's tracks on demand, fetching the next page only
+    /// once the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `genre` - Path of the genre, from `Genre::path`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::{StreamExt, TryStreamExt};
+    ///
+    /// let tracks: Vec<_> = client.genre_tracks_stream("Jazz").take(200).try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn genre_tracks_stream<'a>(
+        &'a self,
+        genre: &'a str,
+    ) -> impl Stream<Item = Result<Track, Error>> + 'a {
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.genre_tracks(genre, Some(offset), Some(limit))
+        })
+    }
+
+    /// Get the playlists of a genre with pagination support.
+    ///
+    /// # Arguments
+    ///
+    /// * `genre` - Path of the genre, from `Genre::path`
+    /// * `offset` - Number of playlists to skip (default: 0)
+    /// * `limit` - Maximum number of playlists to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of the genre's playlists.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let playlists = client.genre_playlists("Jazz", None, Some(20)).await?;
+    /// for playlist in playlists.items {
+    ///     println!("{}", playlist.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn genre_playlists(
+        &self,
+        genre: &str,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<Playlist>, Error> {
+        self.list_at(&format!("genres/{genre}/playlists"), offset, limit)
+            .await
+    }
+
+    /// Stream all playlists of a genre.
+    ///
+    /// Pages through the genre's playlists on demand, fetching the next page only
+    /// once the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `genre` - Path of the genre, from `Genre::path`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let playlists: Vec<_> = client.genre_playlists_stream("Jazz").try_collect().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn genre_playlists_stream<'a>(
+        &'a self,
+        genre: &'a str,
+    ) -> impl Stream<Item = Result<Playlist, Error>> + 'a {
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.genre_playlists(genre, Some(offset), Some(limit))
+        })
+    }
+}
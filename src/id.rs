@@ -0,0 +1,229 @@
+use crate::ResourceType;
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::str::FromStr;
+
+// Defines a strongly typed wrapper around a numeric Tidal identifier
+macro_rules! numeric_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(
+            Debug, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub u64);
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = std::num::ParseIntError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.trim().parse().map($name)
+            }
+        }
+
+        impl From<u64> for $name {
+            fn from(id: u64) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for u64 {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+    };
+}
+
+numeric_id!(
+    /// Unique identifier of a track.
+    TrackId
+);
+
+numeric_id!(
+    /// Unique identifier of an album.
+    AlbumId
+);
+
+numeric_id!(
+    /// Unique identifier of an artist.
+    ArtistId
+);
+
+numeric_id!(
+    /// Unique identifier of a video.
+    VideoId
+);
+
+numeric_id!(
+    /// Unique identifier of a Tidal user.
+    UserId
+);
+
+// Defines a strongly typed wrapper around a string Tidal identifier
+macro_rules! string_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+        #[serde(transparent)]
+        pub struct $name(pub String);
+
+        impl $name {
+            /// Get the identifier as a string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok($name(s.trim().to_string()))
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                $name(id.to_string())
+            }
+        }
+
+        impl From<&String> for $name {
+            fn from(id: &String) -> Self {
+                $name(id.clone())
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(id: &$name) -> Self {
+                id.clone()
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+    };
+}
+
+string_id!(
+    /// Unique identifier of a playlist (UUID format).
+    PlaylistId
+);
+
+string_id!(
+    /// Unique identifier of a mix, such as an artist or track radio.
+    MixId
+);
+
+/// The identifier of any Tidal resource, tagged with its type.
+///
+/// This is returned by `Resource::id()` so that mixed content, such as search
+/// top hits, can be handled without losing track of which kind of id is which.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Clone)]
+#[serde(tag = "type", content = "id", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ResourceId {
+    /// Artist identifier
+    Artist(ArtistId),
+    /// Album identifier
+    Album(AlbumId),
+    /// Track identifier
+    Track(TrackId),
+    /// Video identifier
+    Video(VideoId),
+    /// Playlist identifier
+    Playlist(PlaylistId),
+    /// User profile identifier
+    UserProfile(UserId),
+}
+
+impl ResourceId {
+    /// Get the type of resource this identifier refers to.
+    pub fn resource_type(&self) -> ResourceType {
+        match self {
+            ResourceId::Artist(_) => ResourceType::Artist,
+            ResourceId::Album(_) => ResourceType::Album,
+            ResourceId::Track(_) => ResourceType::Track,
+            ResourceId::Video(_) => ResourceType::Video,
+            ResourceId::Playlist(_) => ResourceType::Playlist,
+            ResourceId::UserProfile(_) => ResourceType::UserProfile,
+        }
+    }
+}
+
+impl Display for ResourceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResourceId::Artist(id) => id.fmt(f),
+            ResourceId::Album(id) => id.fmt(f),
+            ResourceId::Track(id) => id.fmt(f),
+            ResourceId::Video(id) => id.fmt(f),
+            ResourceId::Playlist(id) => id.fmt(f),
+            ResourceId::UserProfile(id) => id.fmt(f),
+        }
+    }
+}
+
+impl From<TrackId> for ResourceId {
+    fn from(id: TrackId) -> Self {
+        ResourceId::Track(id)
+    }
+}
+
+impl From<AlbumId> for ResourceId {
+    fn from(id: AlbumId) -> Self {
+        ResourceId::Album(id)
+    }
+}
+
+impl From<ArtistId> for ResourceId {
+    fn from(id: ArtistId) -> Self {
+        ResourceId::Artist(id)
+    }
+}
+
+impl From<VideoId> for ResourceId {
+    fn from(id: VideoId) -> Self {
+        ResourceId::Video(id)
+    }
+}
+
+impl From<PlaylistId> for ResourceId {
+    fn from(id: PlaylistId) -> Self {
+        ResourceId::Playlist(id)
+    }
+}
+
+impl From<UserId> for ResourceId {
+    fn from(id: UserId) -> Self {
+        ResourceId::UserProfile(id)
+    }
+}
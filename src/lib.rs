@@ -2,20 +2,50 @@
 
 mod album;
 mod artist;
+mod credits;
+#[cfg(feature = "chrono")]
+mod datetime;
+mod favorites;
+mod featured;
+mod genre;
+mod id;
+mod link;
+mod lyrics;
+mod markup;
+mod mix;
+mod page;
 mod playlist;
+mod profile;
 mod search;
 mod track;
+mod video;
 
 pub use album::*;
 pub use artist::*;
+pub use credits::*;
+#[cfg(feature = "chrono")]
+pub use datetime::{parse_date, parse_timestamp};
+pub use favorites::*;
+pub use genre::*;
+pub use id::*;
+pub use lyrics::*;
+pub use markup::*;
+pub use mix::*;
+pub use page::*;
 pub use playlist::*;
+pub use profile::*;
 pub use search::*;
 pub use track::*;
+pub use video::*;
 
 use arc_swap::ArcSwapOption;
 use async_recursion::async_recursion;
+use futures::{Stream, StreamExt, TryStreamExt, stream};
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
+use std::hash::Hash;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use strum_macros::{AsRefStr, EnumString};
@@ -24,8 +54,48 @@ use tokio::time::sleep;
 
 pub(crate) static TIDAL_AUTH_API_BASE_URL: &str = "https://auth.tidal.com/v1";
 pub(crate) static TIDAL_API_BASE_URL: &str = "https://api.tidal.com/v1";
+pub(crate) static TIDAL_API_V2_BASE_URL: &str = "https://api.tidal.com/v2";
 const INITIAL_BACKOFF_MILLIS: u64 = 100;
 const DEFAULT_MAX_BACKOFF_MILLIS: u64 = 5_000;
+const DEFAULT_MAX_CONCURRENCY: usize = 4;
+const DEFAULT_STREAM_PAGE_SIZE: u32 = 100;
+// Maximum number of ids requested with a single comma-separated `ids` parameter
+const MULTI_ID_CHUNK_SIZE: usize = 50;
+
+/// JSON fields returned by Tidal that are not modelled by a struct.
+///
+/// Available with the `extra-fields` feature. Every model deserialized from
+/// the API keeps its unrecognized keys in an `extra` map of this type, and
+/// writes them back out when serialized, so newly added Tidal fields survive
+/// a round-trip even before this crate knows about them.
+#[cfg(feature = "extra-fields")]
+pub type ExtraFields = serde_json::Map<String, serde_json::Value>;
+
+/// A point in time reported by Tidal, such as when a playlist was created.
+///
+/// With the `chrono` feature this is a `chrono::DateTime<Utc>` parsed from
+/// whichever format Tidal used. Without it, it is the raw string from the API.
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+/// A point in time reported by Tidal, such as when a playlist was created.
+///
+/// With the `chrono` feature this is a `chrono::DateTime<Utc>` parsed from
+/// whichever format Tidal used. Without it, it is the raw string from the API.
+#[cfg(not(feature = "chrono"))]
+pub type Timestamp = String;
+
+/// A calendar date reported by Tidal, such as an album's release date.
+///
+/// With the `chrono` feature this is a `chrono::NaiveDate`. Without it, it is
+/// the raw string from the API.
+#[cfg(feature = "chrono")]
+pub type Date = chrono::NaiveDate;
+/// A calendar date reported by Tidal, such as an album's release date.
+///
+/// With the `chrono` feature this is a `chrono::NaiveDate`. Without it, it is
+/// the raw string from the API.
+#[cfg(not(feature = "chrono"))]
+pub type Date = String;
 
 /// Response from the device authorization endpoint containing the information
 /// needed for the user to complete the OAuth2 device flow.
@@ -53,6 +123,11 @@ pub struct DeviceAuthorizationResponse {
     pub expires_in: u64,
     /// The code the user enters on the authorization page
     pub user_code: String,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// Represents a Tidal user account with all associated profile information.
@@ -78,7 +153,12 @@ pub struct User {
     /// User's country code (e.g., "US", "GB")
     pub country_code: String,
     /// Unix timestamp when the account was created
+    #[cfg(not(feature = "chrono"))]
     pub created: u64,
+    /// When the account was created
+    #[cfg(feature = "chrono")]
+    #[serde(with = "crate::datetime::timestamp")]
+    pub created: Timestamp,
     /// User's email address
     pub email: String,
     /// Whether the email address has been verified
@@ -108,9 +188,14 @@ pub struct User {
     /// User's US state (if provided and in US)
     pub us_state: Option<String>,
     /// Unique user ID
-    pub user_id: u64,
+    pub user_id: UserId,
     /// User's username
     pub username: String,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// Complete authorization token response from Tidal's OAuth2 endpoint.
@@ -141,20 +226,21 @@ pub struct AuthzToken {
     /// User ID (same as user.user_id but as i64)
     #[serde(rename = "user_id")]
     pub user_id: i64,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl AuthzToken {
     pub fn authz(&self) -> Option<Authz> {
-        if let Some(refresh_token) = self.refresh_token.clone() {
-            Some(Authz {
-                access_token: self.access_token.clone(),
-                refresh_token: refresh_token,
-                user_id: self.user_id as u64,
-                country_code: Some(self.user.country_code.clone()),
-            })
-        } else {
-            None
-        }
+        self.refresh_token.clone().map(|refresh_token| Authz {
+            access_token: self.access_token.clone(),
+            refresh_token,
+            user_id: UserId(self.user_id as u64),
+            country_code: Some(self.user.country_code.clone()),
+        })
     }
 }
 
@@ -258,10 +344,28 @@ pub enum Error {
     UserAuthenticationRequired,
     /// Track not found in the specified playlist
     #[error("Track {1} not found on playlist {0}")]
-    PlaylistTrackNotFound(String, u64),
+    PlaylistTrackNotFound(PlaylistId, TrackId),
+    /// The mix page did not contain a mix
+    #[error("Mix {0} not found")]
+    MixNotFound(MixId),
+    /// Tidal has no lyrics for the track
+    #[error("No lyrics available for track {0}")]
+    LyricsNotFound(TrackId),
     /// Exponential backoff exceeded the maximum duration while handling rate limits
     #[error("Hit rate limit backoff ceiling of {0}ms without recovery")]
     RateLimitBackoffExceeded(u64),
+    /// Tidal did not return the requested resource
+    #[error("{kind:?} {0} not found", kind = .0.resource_type())]
+    ResourceNotFound(ResourceId),
+    /// A request covering several ids failed, so every id in it gets the same error
+    #[error("Request for several ids failed: {0}")]
+    BatchFailed(Arc<Error>),
+    /// The URL is not a recognizable link to a Tidal resource
+    #[error("Not a valid Tidal URL: {0}")]
+    InvalidTidalUrl(String),
+    /// A base64-encoded value, such as a playback manifest, could not be decoded
+    #[error(transparent)]
+    Base64Decode(#[from] base64::DecodeError),
 }
 
 /// Callback function type for handling authorization token refresh events.
@@ -316,6 +420,9 @@ pub struct TidalClient {
     on_authz_refresh_callback: Option<AuthzCallback>,
     backoff: Mutex<Option<u64>>,
     max_backoff_millis: Option<u64>,
+    lenient_lists: bool,
+    max_concurrency: Option<usize>,
+    favorite_ids: Mutex<Option<(UserId, FavoriteIds)>>,
 }
 
 /// Authorization tokens and user information for API access.
@@ -348,7 +455,7 @@ pub struct Authz {
     /// Refresh token for obtaining new access tokens
     pub refresh_token: String,
     /// User ID associated with these tokens
-    pub user_id: u64,
+    pub user_id: UserId,
     /// User's country code (affects content availability)
     pub country_code: Option<String>,
 }
@@ -357,13 +464,13 @@ impl Authz {
     pub fn new(
         access_token: String,
         refresh_token: String,
-        user_id: u64,
+        user_id: impl Into<UserId>,
         country_code: Option<String>,
     ) -> Self {
         Self {
             access_token,
             refresh_token,
-            user_id,
+            user_id: user_id.into(),
             country_code,
         }
     }
@@ -395,6 +502,9 @@ impl TidalClient {
             on_authz_refresh_callback: None,
             backoff: Mutex::new(None),
             max_backoff_millis: None,
+            lenient_lists: false,
+            max_concurrency: None,
+            favorite_ids: Mutex::new(None),
         }
     }
 
@@ -588,6 +698,55 @@ impl TidalClient {
         self
     }
 
+    /// Enable or disable lenient decoding of paginated lists using the builder pattern.
+    ///
+    /// By default a single item that fails to deserialize makes the whole `List<T>`
+    /// fail. In lenient mode each item is decoded independently: items that fail are
+    /// dropped from `List::items` and reported in `List::warnings` along with their
+    /// raw JSON, so one malformed track doesn't cost you the entire page.
+    ///
+    /// # Arguments
+    ///
+    /// * `lenient_lists` - Whether to skip list items that fail to deserialize
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tidalrs::TidalClient;
+    ///
+    /// let client = TidalClient::new("client_id".to_string())
+    ///     .with_lenient_lists(true);
+    /// ```
+    pub fn with_lenient_lists(mut self, lenient_lists: bool) -> Self {
+        self.lenient_lists = lenient_lists;
+        self
+    }
+
+    /// Set the maximum number of concurrent requests for bulk operations using the builder pattern.
+    ///
+    /// Bulk methods such as `tracks`, `albums` and `artists`, and the `_all` variants
+    /// of paged endpoints, issue several requests at once. This setting bounds how many are in flight at the same time, which
+    /// keeps large imports from tripping Tidal's rate limits.
+    ///
+    /// The default value is 4. A value of `0` is treated as `1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_concurrency` - Maximum number of requests in flight at once
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use tidalrs::TidalClient;
+    ///
+    /// let client = TidalClient::new("client_id".to_string())
+    ///     .with_max_concurrency(8);
+    /// ```
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = Some(max_concurrency);
+        self
+    }
+
     /// Get the current country code for API requests.
     ///
     /// Returns the explicitly set country code, or falls back to the user's
@@ -613,13 +772,13 @@ impl TidalClient {
     ///
     /// Returns the explicitly set device type or `DeviceType::Browser` as default.
     pub fn get_device_type(&self) -> DeviceType {
-        self.device_type.unwrap_or_else(|| DeviceType::Browser)
+        self.device_type.unwrap_or(DeviceType::Browser)
     }
 
     /// Get the current user ID if authenticated.
     ///
     /// Returns `None` if the client is not authenticated.
-    pub fn get_user_id(&self) -> Option<u64> {
+    pub fn get_user_id(&self) -> Option<UserId> {
         self.get_authz().map(|authz| authz.user_id)
     }
 
@@ -670,6 +829,36 @@ impl TidalClient {
             .unwrap_or(DEFAULT_MAX_BACKOFF_MILLIS)
     }
 
+    /// Set the maximum number of concurrent requests for bulk operations.
+    ///
+    /// See `with_max_concurrency` for details.
+    pub fn set_max_concurrency(&mut self, max_concurrency: usize) {
+        self.max_concurrency = Some(max_concurrency);
+    }
+
+    /// Get the maximum number of concurrent requests for bulk operations.
+    ///
+    /// Returns the configured value or the default (4), and never less than 1.
+    pub fn get_max_concurrency(&self) -> usize {
+        self.max_concurrency
+            .unwrap_or(DEFAULT_MAX_CONCURRENCY)
+            .max(1)
+    }
+
+    /// Enable or disable lenient decoding of paginated lists.
+    ///
+    /// See `with_lenient_lists` for details.
+    pub fn set_lenient_lists(&mut self, lenient_lists: bool) {
+        self.lenient_lists = lenient_lists;
+    }
+
+    /// Get whether paginated lists are decoded leniently.
+    ///
+    /// Returns `false` unless lenient decoding has been enabled.
+    pub fn get_lenient_lists(&self) -> bool {
+        self.lenient_lists
+    }
+
     /// Set a callback function to be called when authorization tokens are refreshed.
     ///
     /// This is useful for persisting updated tokens to storage when they are
@@ -679,16 +868,12 @@ impl TidalClient {
     ///
     /// ```no_run
     /// use tidalrs::TidalClient;
-    /// use std::sync::Arc;
     ///
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// let client = TidalClient::new("client_id".to_string())
-    ///     .with_authz_refresh_callback(Arc::new(|new_authz| {
-    ///         println!("Tokens refreshed for user: {}", new_authz.user_id);
-    ///         // Save tokens to persistent storage
-    ///     }));
-    /// # Ok(())
-    /// # }
+    /// let mut client = TidalClient::new("client_id".to_string());
+    /// client.on_authz_refresh(|new_authz| {
+    ///     println!("Tokens refreshed for user: {}", new_authz.user_id);
+    ///     // Save tokens to persistent storage
+    /// });
     /// ```
     pub fn on_authz_refresh<F>(&mut self, f: F)
     where
@@ -723,10 +908,7 @@ impl TidalClient {
     #[async_recursion]
     async fn refresh_authz(&self) -> Result<(), Error> {
         // Try to become the single refresher
-        let permit: Option<SemaphorePermit> = match self.authz_update_semaphore.try_acquire() {
-            Ok(p) => Some(p),
-            Err(_) => None,
-        };
+        let permit: Option<SemaphorePermit> = self.authz_update_semaphore.try_acquire().ok();
 
         match permit {
             // We're the single refresher, fetch the new authz and update the client
@@ -779,21 +961,25 @@ impl TidalClient {
         }
     }
 
-    // Do a GET or DELETE request to the given URL.
+    // Do a GET, POST, PUT or DELETE request to the given URL.
     #[async_recursion]
-    pub(crate) async fn do_request<T: DeserializeOwned>(
+    pub(crate) async fn do_request<T>(
         &self,
         method: reqwest::Method,
         url: &str,
         params: Option<serde_json::Value>,
         etag: Option<&str>,
-    ) -> Result<T, Error> {
+    ) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
         self.await_rate_limit_backoff().await;
 
         let mut req = match method {
             reqwest::Method::GET => self.client.get(url),
             reqwest::Method::DELETE => self.client.delete(url),
             reqwest::Method::POST => self.client.post(url),
+            reqwest::Method::PUT => self.client.put(url),
             _ => panic!("Invalid method: {}", method),
         };
 
@@ -815,6 +1001,7 @@ impl TidalClient {
                 reqwest::Method::POST => req = req.form(params),
                 reqwest::Method::GET => req = req.query(params),
                 reqwest::Method::DELETE => req = req.query(params),
+                reqwest::Method::PUT => req = req.query(params),
                 _ => panic!("Invalid method for params: {}", method),
             }
         }
@@ -864,13 +1051,13 @@ impl TidalClient {
             self.reset_rate_limit_backoff();
 
             // If we have an etag, add it to the response, if the value doesn't already exist
-            if let Some(etag) = etag {
-                if value.get("etag").is_none() {
-                    value["etag"] = serde_json::Value::String(etag);
-                }
+            if let Some(etag) = etag
+                && value.get("etag").is_none()
+            {
+                value["etag"] = serde_json::Value::String(etag);
             }
 
-            let resp: T = match serde_json::from_value(value.clone()) {
+            let resp: T = match decode_value(value.clone(), self.lenient_lists) {
                 Ok(t) => t,
                 Err(e) => {
                     if log::log_enabled!(log::Level::Warn) {
@@ -937,6 +1124,281 @@ impl TidalClient {
         }
     }
 
+    // Run `fetch` for every id with at most `max_concurrency` requests in flight,
+    // returning each id's result in the same order as the input.
+    pub(crate) async fn fetch_concurrently<I, T, F, Fut>(
+        &self,
+        ids: &[I],
+        fetch: F,
+    ) -> Vec<(I, Result<T, Error>)>
+    where
+        I: Clone,
+        F: Fn(I) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        stream::iter(ids.iter().cloned())
+            .map(|id| {
+                let fut = fetch(id.clone());
+                async move { (id, fut.await) }
+            })
+            .buffered(self.get_max_concurrency())
+            .collect()
+            .await
+    }
+
+    // Fetch a page of a catalog collection, such as featured/new/albums, with the
+    // usual paging defaults
+    pub(crate) async fn list_at<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<T>, Error> {
+        let url = format!("{TIDAL_API_BASE_URL}/{path}");
+        let params = self.list_params(offset, limit);
+
+        self.do_request(reqwest::Method::GET, &url, Some(params), None)
+            .await
+    }
+
+    // The query of one page of a catalog collection, in the client's country
+    fn list_params(&self, offset: Option<u32>, limit: Option<u32>) -> serde_json::Value {
+        serde_json::json!({
+            "offset": offset.unwrap_or(0),
+            "limit": limit.unwrap_or(100),
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        })
+    }
+
+    // Fetch resources from an endpoint that takes comma-separated ids, such as
+    // /tracks?ids=1,2,3, using `fetch_one` for ids that have to be fetched on their own.
+    // Every id gets its own result, in input order.
+    pub(crate) async fn fetch_by_ids<I, T, F, Fut>(
+        &self,
+        path: &str,
+        ids: &[I],
+        fetch_one: F,
+        id_of: fn(&T) -> I,
+        resource: fn(I) -> ResourceId,
+    ) -> Vec<(I, Result<T, Error>)>
+    where
+        I: Display + Clone + Eq + Hash,
+        T: DeserializeOwned + Clone,
+        F: Fn(I) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        self.fetch_in_chunks(
+            ids,
+            |ids| self.fetch_ids_chunk(path, ids),
+            fetch_one,
+            id_of,
+            resource,
+        )
+        .await
+    }
+
+    // Fetch ids in chunks with `fetch_chunk`, which takes comma-separated ids and returns
+    // the raw list of items, and match the items back to the requested ids.
+    //
+    // Chunks are always decoded leniently, so a malformed item only fails its own id. A
+    // chunk that Tidal rejects because of its ids is retried one id at a time.
+    async fn fetch_in_chunks<I, T, C, CFut, F, FFut>(
+        &self,
+        ids: &[I],
+        fetch_chunk: C,
+        fetch_one: F,
+        id_of: fn(&T) -> I,
+        resource: fn(I) -> ResourceId,
+    ) -> Vec<(I, Result<T, Error>)>
+    where
+        I: Display + Clone + Eq + Hash,
+        T: DeserializeOwned + Clone,
+        C: Fn(String) -> CFut,
+        CFut: Future<Output = Result<serde_json::Value, Error>>,
+        F: Fn(I) -> FFut,
+        FFut: Future<Output = Result<T, Error>>,
+    {
+        let chunks: Vec<&[I]> = ids.chunks(MULTI_ID_CHUNK_SIZE).collect();
+        let pages = self
+            .fetch_concurrently(&chunks, |chunk| {
+                let ids = chunk
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                fetch_chunk(ids)
+            })
+            .await;
+
+        let mut results = Vec::with_capacity(ids.len());
+        for (chunk, page) in pages {
+            let page = page.and_then(|value| Ok(decode_value::<List<T>>(value, true)?));
+            match page {
+                Err(err) if chunk.len() > 1 && is_id_error(&err) => {
+                    results.extend(self.fetch_concurrently(chunk, &fetch_one).await)
+                }
+                page => results.extend(match_ids(chunk, page, id_of, resource)),
+            }
+        }
+
+        results
+    }
+
+    async fn fetch_ids_chunk(&self, path: &str, ids: String) -> Result<serde_json::Value, Error> {
+        let url = format!("{TIDAL_API_BASE_URL}/{path}");
+
+        let params = serde_json::json!({
+            "ids": ids,
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        self.do_request(reqwest::Method::GET, &url, Some(params), None)
+            .await
+    }
+
+    // Fetch every item of a paginated endpoint.
+    //
+    // The first page is fetched on its own to learn the total, then the remaining pages
+    // are fetched concurrently and joined in order. Items repeated across a page boundary,
+    // which happens when the collection grows while it is being read, are only kept once.
+    pub(crate) async fn fetch_all<T, K, F, Fut>(
+        &self,
+        page_size: u32,
+        fetch: F,
+        key: fn(&T) -> K,
+    ) -> Result<Vec<T>, Error>
+    where
+        K: PartialEq,
+        F: Fn(u32, u32) -> Fut,
+        Fut: Future<Output = Result<List<T>, Error>>,
+    {
+        let pages = self.fetch_pages(page_size, fetch).await?;
+        Ok(join_pages(pages, key))
+    }
+
+    // Fetch every item of a paginated endpoint once per query, such as once per country,
+    // keeping an item that more than one query found only the first time, by `key`.
+    pub(crate) async fn fetch_all_each<Q, T, K, F, Fut>(
+        &self,
+        queries: Vec<Q>,
+        fetch: F,
+        key: fn(&T) -> K,
+    ) -> Result<Vec<T>, Error>
+    where
+        Q: Clone,
+        K: Eq + Hash,
+        F: Fn(Q, u32, u32) -> Fut,
+        Fut: Future<Output = Result<List<T>, Error>>,
+    {
+        let mut seen = HashSet::new();
+        let mut items = Vec::new();
+        for query in queries {
+            let found = self
+                .fetch_all(
+                    DEFAULT_STREAM_PAGE_SIZE,
+                    |offset, limit| fetch(query.clone(), offset, limit),
+                    key,
+                )
+                .await?;
+            items.extend(found.into_iter().filter(|item| seen.insert(key(item))));
+        }
+
+        Ok(items)
+    }
+
+    // Look up the items of a catalog endpoint, such as /tracks?isrc=, by an ISRC or UPC in
+    // each country. Every form the code can be listed under is looked up.
+    pub(crate) async fn catalog_lookup<T, K>(
+        &self,
+        path: &str,
+        field: &str,
+        code: &str,
+        countries: &[&str],
+        key: fn(&T) -> K,
+    ) -> Result<Vec<T>, Error>
+    where
+        T: DeserializeOwned,
+        K: Eq + Hash,
+    {
+        let url = format!("{TIDAL_API_BASE_URL}/{path}");
+        let forms = catalog_code_forms(code);
+        let queries: Vec<(&str, &str)> = countries
+            .iter()
+            .flat_map(|country| forms.iter().map(move |code| (code.as_str(), *country)))
+            .collect();
+
+        self.fetch_all_each(
+            queries,
+            |(code, country), offset, limit| {
+                let params = self.catalog_lookup_params(field, code, country, offset, limit);
+                self.do_request(reqwest::Method::GET, &url, Some(params), None)
+            },
+            key,
+        )
+        .await
+    }
+
+    // The query of one page of a catalog lookup
+    fn catalog_lookup_params(
+        &self,
+        field: &str,
+        code: &str,
+        country: &str,
+        offset: u32,
+        limit: u32,
+    ) -> serde_json::Value {
+        let mut params = serde_json::json!({
+            "offset": offset,
+            "limit": limit,
+            "countryCode": country,
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+        params[field] = serde_json::Value::String(code.to_string());
+        params
+    }
+
+    // Fetch every page of a paginated endpoint, in order.
+    //
+    // Use this over `fetch_all` for collections that can legitimately repeat an item,
+    // such as playlists, where dropping a repeat at a page boundary would lose it.
+    pub(crate) async fn fetch_pages<T, F, Fut>(
+        &self,
+        page_size: u32,
+        fetch: F,
+    ) -> Result<Vec<Vec<T>>, Error>
+    where
+        F: Fn(u32, u32) -> Fut,
+        Fut: Future<Output = Result<List<T>, Error>>,
+    {
+        let first = fetch(0, page_size).await?;
+
+        // Tidal may return fewer items than requested, so page by what it actually returned
+        let step = first.items.len() + first.warnings.len();
+        if step == 0 {
+            return Ok(vec![first.items]);
+        }
+
+        let offsets: Vec<u32> = (step..first.total)
+            .step_by(step)
+            .map(|offset| offset as u32)
+            .collect();
+        let pages = self
+            .fetch_concurrently(&offsets, |offset| fetch(offset, step as u32))
+            .await;
+
+        let mut items = vec![first.items];
+        for (_, page) in pages {
+            items.push(page?.items);
+        }
+
+        Ok(items)
+    }
+
     async fn await_rate_limit_backoff(&self) {
         // Skip backoff if disabled
         if self.get_max_backoff_millis() == 0 {
@@ -951,10 +1413,10 @@ impl TidalClient {
             *guard
         };
 
-        if let Some(ms) = delay {
-            if ms > 0 {
-                sleep(Duration::from_millis(ms)).await;
-            }
+        if let Some(ms) = delay
+            && ms > 0
+        {
+            sleep(Duration::from_millis(ms)).await;
         }
     }
 
@@ -1052,6 +1514,7 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// use tidalrs::TidalClient;
     ///
     /// let mut client = TidalClient::new("client_id".to_string());
@@ -1063,6 +1526,8 @@ impl TidalClient {
     /// // Get the authz token to store in persistent storage
     /// let authz = authz_token.authz().unwrap();
     /// std::fs::write("authz.json", serde_json::to_string(&authz).unwrap()).unwrap();
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn authorize(
         &self,
@@ -1175,6 +1640,11 @@ pub struct MediaMetadata {
     /// Tags associated with the media
     #[serde(default)]
     pub tags: Vec<String>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// Types of resources available in the Tidal API.
@@ -1259,31 +1729,43 @@ pub enum Resource {
     Tracks(Track),
     /// Playlist resource
     Playlists(Playlist),
-
-    // TODO: Add proper support for videos and user profiles
-    /// Video resource (currently as raw JSON)
-    Videos(serde_json::Value),
-    /// User profile resource (currently as raw JSON)
-    UserProfiles(serde_json::Value),
+    /// Video resource
+    Videos(Video),
+    /// User profile resource
+    UserProfiles(UserProfile),
 }
 
 impl Resource {
-    pub fn id(&self) -> String {
+    /// Get the typed identifier of this resource.
+    pub fn id(&self) -> ResourceId {
         match self {
-            Resource::Artists(artist) => artist.id.to_string(),
-            Resource::Albums(album) => album.id.to_string(),
-            Resource::Tracks(track) => track.id.to_string(),
-            Resource::Playlists(playlist) => playlist.uuid.to_string(),
-            Resource::Videos(video) => video
-                .get("id")
-                .unwrap_or(&serde_json::Value::Null)
-                .to_string(),
-            Resource::UserProfiles(user_profile) => user_profile
-                .get("id")
-                .unwrap_or(&serde_json::Value::Null)
-                .to_string(),
+            Resource::Artists(artist) => ResourceId::Artist(artist.id),
+            Resource::Albums(album) => ResourceId::Album(album.id),
+            Resource::Tracks(track) => ResourceId::Track(track.id),
+            Resource::Playlists(playlist) => ResourceId::Playlist(playlist.uuid.clone()),
+            Resource::Videos(video) => ResourceId::Video(video.id),
+            Resource::UserProfiles(user_profile) => ResourceId::UserProfile(user_profile.user_id),
         }
     }
+
+    /// Generate the canonical Tidal share URL for this resource.
+    pub fn share_url(&self) -> String {
+        self.id().share_url()
+    }
+}
+
+/// A page of results from a cursor-paginated endpoint.
+///
+/// Newer Tidal endpoints, such as profiles and follows, page with an opaque
+/// cursor instead of an offset. Pass `cursor` back to the same method to fetch
+/// the next page; it is `None` on the last page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CursorList<T> {
+    /// Items in the current page
+    pub items: Vec<T>,
+    /// Cursor for the next page, if there is one
+    #[serde(default)]
+    pub cursor: Option<String>,
 }
 
 /// A paginated list response from the Tidal API.
@@ -1307,7 +1789,7 @@ impl Resource {
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct List<T> {
     /// Items in the current page
     pub items: Vec<T>,
@@ -1321,8 +1803,14 @@ pub struct List<T> {
 
     /// ETag for optimistic concurrency control (used in playlist modifications)
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(default)]
     pub etag: Option<String>,
+
+    /// Items that were skipped because they failed to deserialize
+    ///
+    /// This is only ever populated when the client has lenient list decoding enabled,
+    /// see `TidalClient::with_lenient_lists`.
+    #[serde(skip)]
+    pub warnings: Vec<ListItemWarning>,
 }
 
 impl<T> List<T> {
@@ -1331,9 +1819,32 @@ impl<T> List<T> {
     }
 
     // The number of items left to fetch
+    //
+    // Saturates at zero, since the total can shrink while a collection is being paged through.
     pub fn num_left(&self) -> usize {
-        let current_batch_size = self.items.len();
-        self.total - self.offset - current_batch_size
+        let current_batch_size = self.items.len() + self.warnings.len();
+        self.total
+            .saturating_sub(self.offset)
+            .saturating_sub(current_batch_size)
+    }
+}
+
+impl<T> List<T>
+where
+    T: DeserializeOwned,
+{
+    /// Deserialize a list from raw JSON, skipping items that fail to deserialize.
+    ///
+    /// This is the same lenient decoding the client performs when
+    /// `with_lenient_lists(true)` is set, and is useful for lists that were
+    /// fetched or cached as raw JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the list envelope itself (offset, limit, total) is invalid.
+    /// Invalid items are reported in `List::warnings` instead.
+    pub fn from_value_lenient(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        decode_value(value, true)
     }
 }
 
@@ -1345,7 +1856,278 @@ impl<T> Default for List<T> {
             limit: 0,
             total: 0,
             etag: None,
+            warnings: Vec::new(),
+        }
+    }
+}
+
+impl<'de, T> Deserialize<'de> for List<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct RawList {
+            items: Vec<serde_json::Value>,
+            offset: usize,
+            limit: usize,
+            #[serde(rename = "totalNumberOfItems")]
+            total: usize,
+            #[serde(default)]
+            etag: Option<String>,
+        }
+
+        let raw = RawList::deserialize(deserializer)?;
+        let lenient = LENIENT_LISTS.with(Cell::get);
+
+        // Decode each item on its own so that a single bad item can be skipped in lenient mode
+        let mut items = Vec::with_capacity(raw.items.len());
+        let mut warnings = Vec::new();
+        for (index, raw_item) in raw.items.into_iter().enumerate() {
+            match T::deserialize(&raw_item) {
+                Ok(item) => items.push(item),
+                Err(e) if lenient => {
+                    log::warn!(
+                        "Skipping list item {} that failed to deserialize: {}",
+                        index,
+                        e
+                    );
+                    warnings.push(ListItemWarning {
+                        index,
+                        message: e.to_string(),
+                        raw: raw_item,
+                    });
+                }
+                Err(e) => return Err(serde::de::Error::custom(e)),
+            }
         }
+
+        Ok(List {
+            items,
+            offset: raw.offset,
+            limit: raw.limit,
+            total: raw.total,
+            etag: raw.etag,
+            warnings,
+        })
+    }
+}
+
+// Page through a paginated endpoint, yielding its items one at a time.
+//
+// `fetch` is called with an offset and limit for each page. Paging stops once a page
+// comes back empty or the next offset reaches the most recently reported total, so the
+// stream ends cleanly even if the collection shrinks or grows while it is being read.
+pub(crate) fn paginate<'a, T, F, Fut>(
+    page_size: u32,
+    fetch: F,
+) -> impl Stream<Item = Result<T, Error>> + 'a
+where
+    T: 'a,
+    F: Fn(u32, u32) -> Fut + 'a,
+    Fut: Future<Output = Result<List<T>, Error>> + 'a,
+{
+    paginate_positioned(page_size, fetch).map_ok(|(_, item)| item)
+}
+
+// Like `paginate`, but yields every item with its position in the whole collection.
+//
+// Positions count the items that lenient decoding skipped, so they can be used to address
+// items on the server, such as when removing a playlist item by its index.
+pub(crate) fn paginate_positioned<'a, T, F, Fut>(
+    page_size: u32,
+    fetch: F,
+) -> impl Stream<Item = Result<(usize, T), Error>> + 'a
+where
+    T: 'a,
+    F: Fn(u32, u32) -> Fut + 'a,
+    Fut: Future<Output = Result<List<T>, Error>> + 'a,
+{
+    stream::try_unfold(Some(0u32), move |next_offset| {
+        let page = next_offset.map(|offset| (offset, fetch(offset, page_size)));
+        async move {
+            let Some((offset, page)) = page else {
+                return Ok::<_, Error>(None);
+            };
+            let page = page.await?;
+
+            // Skipped items still count towards the offset of the next page
+            let consumed = page.items.len() + page.warnings.len();
+            let next_offset = offset as usize + consumed;
+            let next_offset = if consumed == 0 || next_offset >= page.total {
+                None
+            } else {
+                Some(next_offset as u32)
+            };
+
+            let skipped: Vec<usize> = page.warnings.iter().map(|w| w.index).collect();
+            let positions = (0..)
+                .filter(move |index| !skipped.contains(index))
+                .map(move |index| offset as usize + index);
+
+            Ok(Some((positions.zip(page.items), next_offset)))
+        }
+    })
+    .map_ok(|items| stream::iter(items.map(Ok)))
+    .try_flatten()
+}
+
+// Join pages in order, keeping items repeated across a page boundary only once
+fn join_pages<T, K: PartialEq>(pages: Vec<Vec<T>>, key: fn(&T) -> K) -> Vec<T> {
+    let mut items = Vec::new();
+    for page in pages {
+        let overlap = boundary_overlap(&items, &page, key);
+        items.extend(page.into_iter().skip(overlap));
+    }
+    items
+}
+
+// The length of the longest run of items at the end of `previous` that is repeated at the
+// start of `next`, comparing items by `key`.
+fn boundary_overlap<T, K: PartialEq>(previous: &[T], next: &[T], key: fn(&T) -> K) -> usize {
+    let max_overlap = previous.len().min(next.len());
+    (1..=max_overlap)
+        .rev()
+        .find(|&len| {
+            previous[previous.len() - len..]
+                .iter()
+                .zip(&next[..len])
+                .all(|(a, b)| key(a) == key(b))
+        })
+        .unwrap_or(0)
+}
+
+// Match the items returned for a chunk of ids back to the requested ids.
+//
+// An id missing from the response wasn't found, and an id whose item was skipped by
+// lenient decoding gets that item's decoding error. A failed request fails every id in it.
+pub(crate) fn match_ids<I, T>(
+    ids: &[I],
+    page: Result<List<T>, Error>,
+    id_of: fn(&T) -> I,
+    resource: fn(I) -> ResourceId,
+) -> Vec<(I, Result<T, Error>)>
+where
+    I: Display + Clone + Eq + Hash,
+    T: Clone,
+{
+    let page = match page {
+        Ok(page) => page,
+        Err(err) => return share_error(ids, err),
+    };
+    let found: HashMap<I, T> = page
+        .items
+        .into_iter()
+        .map(|item| (id_of(&item), item))
+        .collect();
+
+    ids.iter()
+        .map(|id| {
+            let result = match found.get(id) {
+                Some(item) => Ok(item.clone()),
+                None => match page.warnings.iter().find(|w| raw_id_matches(&w.raw, id)) {
+                    Some(warning) => {
+                        Err(Error::SerdeJson(serde::de::Error::custom(&warning.message)))
+                    }
+                    None => Err(Error::ResourceNotFound(resource(id.clone()))),
+                },
+            };
+            (id.clone(), result)
+        })
+        .collect()
+}
+
+// Whether Tidal rejected a request because of the ids in it, such as an id that doesn't exist
+pub(crate) fn is_id_error(err: &Error) -> bool {
+    matches!(err, Error::TidalApiError(err) if matches!(err.status, 400 | 404))
+}
+
+// Give every id of a failed request that covered several ids the same error
+pub(crate) fn share_error<I: Clone, T>(ids: &[I], err: Error) -> Vec<(I, Result<T, Error>)> {
+    if let [id] = ids {
+        return vec![(id.clone(), Err(err))];
+    }
+
+    let err = Arc::new(err);
+    ids.iter()
+        .map(|id| (id.clone(), Err(Error::BatchFailed(err.clone()))))
+        .collect()
+}
+
+// Whether the raw JSON of an item has the given id, which Tidal sends as a number or a string
+fn raw_id_matches(raw: &serde_json::Value, id: &impl Display) -> bool {
+    let id = id.to_string();
+    match raw.get("id") {
+        Some(serde_json::Value::String(raw_id)) => *raw_id == id,
+        Some(serde_json::Value::Number(raw_id)) => raw_id.to_string() == id,
+        _ => false,
+    }
+}
+
+/// A list item that was skipped during lenient list decoding.
+///
+/// This keeps the raw JSON of the item so that callers can inspect it,
+/// log it, or attempt their own recovery.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListItemWarning {
+    /// Position of the item within the page
+    pub index: usize,
+    /// Why the item failed to deserialize
+    pub message: String,
+    /// The raw JSON of the item
+    pub raw: serde_json::Value,
+}
+
+thread_local! {
+    // Whether `List<T>` deserialization on this thread should skip bad items rather than fail
+    static LENIENT_LISTS: Cell<bool> = const { Cell::new(false) };
+}
+
+// Deserialize a value, decoding any `List<T>` within it leniently if requested.
+//
+// Deserialization is synchronous, so the thread-local flag can't leak into other requests.
+pub(crate) fn decode_value<T>(
+    value: serde_json::Value,
+    lenient_lists: bool,
+) -> Result<T, serde_json::Error>
+where
+    T: DeserializeOwned,
+{
+    let previous = LENIENT_LISTS.replace(lenient_lists);
+    let result = serde_json::from_value(value);
+    LENIENT_LISTS.set(previous);
+    result
+}
+
+// Normalize an ISRC or UPC as written by people, e.g. "us-rh1-97-00001" becomes "USRH19700001"
+pub(crate) fn normalize_catalog_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+// Every form a catalog code can be listed under. A 12-digit UPC is the same code as the
+// 13-digit EAN made by putting a zero in front of it, so both forms are returned.
+pub(crate) fn catalog_code_forms(code: &str) -> Vec<String> {
+    let code = normalize_catalog_code(code);
+    if code.is_empty() || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return vec![code];
+    }
+
+    match code.len() {
+        12 => {
+            let ean = format!("0{code}");
+            vec![code, ean]
+        }
+        13 if code.starts_with('0') => {
+            let upc = code[1..].to_string();
+            vec![code, upc]
+        }
+        _ => vec![code],
     }
 }
 
@@ -1357,3 +2139,404 @@ where
 {
     Option::deserialize(deserializer).map(|opt| opt.unwrap_or_default())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Item {
+        id: TrackId,
+    }
+
+    fn list(items: Vec<Item>) -> List<Item> {
+        List {
+            total: items.len(),
+            limit: items.len(),
+            items,
+            ..Default::default()
+        }
+    }
+
+    fn api_error(status: u16) -> Error {
+        Error::TidalApiError(TidalApiError {
+            status,
+            sub_status: 0,
+            user_message: String::new(),
+        })
+    }
+
+    #[test]
+    fn test_match_ids_keeps_request_order() {
+        let ids = [TrackId(1), TrackId(2), TrackId(3), TrackId(1)];
+        let page = list(vec![Item { id: TrackId(3) }, Item { id: TrackId(1) }]);
+
+        let results = match_ids(&ids, Ok(page), |item| item.id, ResourceId::Track);
+
+        let ids: Vec<_> = results.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, [TrackId(1), TrackId(2), TrackId(3), TrackId(1)]);
+        assert_eq!(results[0].1.as_ref().unwrap().id, TrackId(1));
+        assert!(matches!(
+            results[1].1,
+            Err(Error::ResourceNotFound(ResourceId::Track(TrackId(2))))
+        ));
+        assert_eq!(results[2].1.as_ref().unwrap().id, TrackId(3));
+        assert_eq!(results[3].1.as_ref().unwrap().id, TrackId(1));
+    }
+
+    #[test]
+    fn test_match_ids_reports_skipped_items() {
+        let ids = [TrackId(1), TrackId(2)];
+        let mut page = list(vec![Item { id: TrackId(1) }]);
+        page.warnings.push(ListItemWarning {
+            index: 1,
+            message: "missing field `title`".to_string(),
+            raw: serde_json::json!({"id": 2}),
+        });
+
+        let results = match_ids(&ids, Ok(page), |item| item.id, ResourceId::Track);
+
+        assert!(results[0].1.is_ok());
+        assert!(
+            matches!(&results[1].1, Err(Error::SerdeJson(e)) if e.to_string().contains("title"))
+        );
+    }
+
+    // A raw list of items with the given ids, as returned for a chunk of ids
+    fn raw_list(ids: &str) -> serde_json::Value {
+        let items: Vec<_> = ids
+            .split(',')
+            .map(|id| serde_json::json!({"id": id.parse::<u64>().unwrap()}))
+            .collect();
+        serde_json::json!({
+            "limit": items.len(),
+            "offset": 0,
+            "totalNumberOfItems": items.len(),
+            "items": items,
+        })
+    }
+
+    async fn fetch_one(id: TrackId) -> Result<Item, Error> {
+        match id {
+            TrackId(7) => Err(api_error(404)),
+            id => Ok(Item { id }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_in_chunks_keeps_items_around_a_malformed_one() {
+        let client = TidalClient::new("client_id".to_string());
+        let ids: Vec<TrackId> = (1..=50).map(TrackId).collect();
+
+        // Item 7 has a field the type doesn't accept, even though the client isn't lenient
+        let fetch_chunk = |ids: String| async move {
+            let mut list = raw_list(&ids);
+            list["items"][6]["unexpected"] = serde_json::json!(true);
+            Ok(list)
+        };
+        let results = client
+            .fetch_in_chunks(
+                &ids,
+                fetch_chunk,
+                fetch_one,
+                |item| item.id,
+                ResourceId::Track,
+            )
+            .await;
+
+        assert_eq!(results.len(), 50);
+        assert!(matches!(results[6], (TrackId(7), Err(Error::SerdeJson(_)))));
+        let found = results.iter().filter(|(_, result)| result.is_ok()).count();
+        assert_eq!(found, 49);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_in_chunks_retries_rejected_chunks_per_id() {
+        let client = TidalClient::new("client_id".to_string());
+        let ids: Vec<TrackId> = (1..=60).map(TrackId).collect();
+
+        // Tidal rejects the chunk holding the unknown id 7
+        let fetch_chunk = |ids: String| async move {
+            if ids.split(',').any(|id| id == "7") {
+                Err(api_error(400))
+            } else {
+                Ok(raw_list(&ids))
+            }
+        };
+        let results = client
+            .fetch_in_chunks(
+                &ids,
+                fetch_chunk,
+                fetch_one,
+                |item| item.id,
+                ResourceId::Track,
+            )
+            .await;
+
+        let ids: Vec<_> = results.iter().map(|(id, _)| id.0).collect();
+        assert_eq!(ids, (1..=60).collect::<Vec<_>>());
+        assert!(matches!(&results[6].1, Err(Error::TidalApiError(e)) if e.status == 404));
+        assert_eq!(
+            results.iter().filter(|(_, result)| result.is_ok()).count(),
+            59
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_in_chunks_shares_other_errors() {
+        let client = TidalClient::new("client_id".to_string());
+        let ids: Vec<TrackId> = (1..=3).map(TrackId).collect();
+
+        // Fetching the ids one by one would succeed, so errors show they weren't retried
+        let fetch_chunk = |_ids: String| async move { Err(api_error(503)) };
+        let results = client
+            .fetch_in_chunks(
+                &ids,
+                fetch_chunk,
+                fetch_one,
+                |item| item.id,
+                ResourceId::Track,
+            )
+            .await;
+
+        assert!(
+            results
+                .iter()
+                .all(|(_, result)| matches!(result, Err(Error::BatchFailed(_))))
+        );
+    }
+
+    #[test]
+    fn test_match_ids_shares_a_failed_request() {
+        let ids = [TrackId(1), TrackId(2)];
+
+        let results = match_ids(
+            &ids,
+            Err(api_error(500)),
+            |item: &Item| item.id,
+            ResourceId::Track,
+        );
+
+        assert_eq!(results.len(), 2);
+        for (_, result) in &results {
+            assert!(matches!(
+                result,
+                Err(Error::BatchFailed(err)) if matches!(**err, Error::TidalApiError(ref e) if e.status == 500)
+            ));
+        }
+
+        // A single id keeps the error as it is
+        let results = match_ids(
+            &ids[..1],
+            Err(api_error(500)),
+            |item: &Item| item.id,
+            ResourceId::Track,
+        );
+        assert!(matches!(results[0].1, Err(Error::TidalApiError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_paginate_positions_count_skipped_items() {
+        // Two pages of three, where the second item of the first page failed to decode
+        let pages = |offset: u32, _limit: u32| async move {
+            let mut page = match offset {
+                0 => list(vec![Item { id: TrackId(1) }, Item { id: TrackId(3) }]),
+                3 => list(vec![Item { id: TrackId(4) }, Item { id: TrackId(5) }]),
+                _ => list(Vec::new()),
+            };
+            if offset == 0 {
+                page.warnings.push(ListItemWarning {
+                    index: 1,
+                    message: "missing field `title`".to_string(),
+                    raw: serde_json::json!({"id": 2}),
+                });
+            }
+            page.offset = offset as usize;
+            page.total = 5;
+            Ok(page)
+        };
+
+        let items: Vec<(usize, Item)> = paginate_positioned(3, pages).try_collect().await.unwrap();
+        let positions: Vec<(usize, TrackId)> = items
+            .into_iter()
+            .map(|(index, item)| (index, item.id))
+            .collect();
+        assert_eq!(
+            positions,
+            [
+                (0, TrackId(1)),
+                (2, TrackId(3)),
+                (3, TrackId(4)),
+                (4, TrackId(5))
+            ]
+        );
+
+        let ids: Vec<Item> = paginate(3, pages).try_collect().await.unwrap();
+        assert_eq!(ids.len(), 4);
+    }
+
+    // Pages of tracks by id, with a total of all the ids given
+    fn pages_of(
+        pages: &[&[u64]],
+    ) -> impl Fn(u32, u32) -> std::future::Ready<Result<List<Item>, Error>> {
+        let total = pages.iter().map(|page| page.len()).sum();
+        let pages: Vec<(usize, Vec<u64>)> = pages
+            .iter()
+            .scan(0, |offset, page| {
+                let start = *offset;
+                *offset += page.len();
+                Some((start, page.to_vec()))
+            })
+            .collect();
+
+        move |offset, _limit| {
+            let ids = pages
+                .iter()
+                .find(|(start, _)| *start == offset as usize)
+                .map(|(_, ids)| ids.clone())
+                .unwrap_or_default();
+            let mut page = list(ids.into_iter().map(|id| Item { id: TrackId(id) }).collect());
+            page.offset = offset as usize;
+            page.total = total;
+            std::future::ready(Ok(page))
+        }
+    }
+
+    fn ids(items: &[Item]) -> Vec<u64> {
+        items.iter().map(|item| item.id.0).collect()
+    }
+
+    #[test]
+    fn test_boundary_overlap() {
+        let items =
+            |ids: &[u64]| -> Vec<Item> { ids.iter().map(|&id| Item { id: TrackId(id) }).collect() };
+        let key = |item: &Item| item.id;
+
+        assert_eq!(
+            boundary_overlap(&items(&[1, 2, 3]), &items(&[2, 3, 4]), key),
+            2
+        );
+        assert_eq!(
+            boundary_overlap(&items(&[1, 2, 3]), &items(&[4, 5, 6]), key),
+            0
+        );
+        assert_eq!(boundary_overlap(&items(&[1, 2]), &items(&[1, 2]), key), 2);
+        assert_eq!(boundary_overlap(&items(&[]), &items(&[1]), key), 0);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_drops_exact_overlap() {
+        let client = TidalClient::new("client_id".to_string());
+
+        // An item was added at the start while paging, pushing 3 onto the second page too
+        let fetch = pages_of(&[&[1, 2, 3], &[3, 4, 5]]);
+        let items = client.fetch_all(3, fetch, |item| item.id).await.unwrap();
+
+        assert_eq!(ids(&items), [1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_without_overlap() {
+        let client = TidalClient::new("client_id".to_string());
+
+        let fetch = pages_of(&[&[1, 2, 3], &[4, 5, 6], &[7]]);
+        let items = client.fetch_all(3, fetch, |item| item.id).await.unwrap();
+
+        assert_eq!(ids(&items), [1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pages_keeps_legitimate_repeats() {
+        let client = TidalClient::new("client_id".to_string());
+
+        // The same track is on a playlist twice, either side of a page boundary
+        let fetch = pages_of(&[&[1, 2, 3], &[3, 4]]);
+        let pages = client.fetch_pages(3, fetch).await.unwrap();
+        let items: Vec<Item> = pages.into_iter().flatten().collect();
+
+        assert_eq!(ids(&items), [1, 2, 3, 3, 4]);
+
+        // Repeats within a page are kept by `fetch_all` too
+        let fetch = pages_of(&[&[1, 1, 2], &[3, 1]]);
+        let items = client.fetch_all(3, fetch, |item| item.id).await.unwrap();
+        assert_eq!(ids(&items), [1, 1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn test_normalize_catalog_code() {
+        assert_eq!(normalize_catalog_code("US-RH1-97-00001"), "USRH19700001");
+        assert_eq!(normalize_catalog_code(" 0 75596 29056 2 "), "075596290562");
+        assert_eq!(normalize_catalog_code("gbaye9200070"), "GBAYE9200070");
+        assert_eq!(normalize_catalog_code("us-rh1 97\t00001"), "USRH19700001");
+        assert_eq!(normalize_catalog_code(""), "");
+        assert_eq!(normalize_catalog_code(" - "), "");
+    }
+
+    #[test]
+    fn test_catalog_code_forms() {
+        // A UPC is also looked up as its EAN, and the other way around
+        assert_eq!(
+            catalog_code_forms("0 75596 29056 2"),
+            ["075596290562", "0075596290562"]
+        );
+        assert_eq!(
+            catalog_code_forms("0075596290562"),
+            ["0075596290562", "075596290562"]
+        );
+
+        // Other codes only have one form
+        assert_eq!(catalog_code_forms("4988005677938"), ["4988005677938"]);
+        assert_eq!(catalog_code_forms("us-rh1-97-00001"), ["USRH19700001"]);
+        assert_eq!(catalog_code_forms("12345"), ["12345"]);
+        assert_eq!(catalog_code_forms(""), [""]);
+    }
+
+    #[test]
+    fn test_catalog_lookup_params() {
+        let client = TidalClient::new("client_id".to_string()).with_country_code("US".to_string());
+
+        let params = client.catalog_lookup_params("upc", "075596290562", "GB", 100, 50);
+
+        assert_eq!(params["upc"], "075596290562");
+        assert_eq!(params["countryCode"], "GB");
+        assert_eq!(params["offset"], 100);
+        assert_eq!(params["limit"], 50);
+        assert!(params.get("isrc").is_none());
+    }
+
+    #[test]
+    fn test_list_params() {
+        let client = TidalClient::new("client_id".to_string()).with_country_code("GB".to_string());
+
+        let params = client.list_params(None, None);
+        assert_eq!(params["countryCode"], "GB");
+        assert_eq!(params["offset"], 0);
+        assert_eq!(params["limit"], 100);
+
+        let params = client.list_params(Some(200), Some(50));
+        assert_eq!(params["offset"], 200);
+        assert_eq!(params["limit"], 50);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_all_each_keeps_items_found_twice_once() {
+        let client = TidalClient::new("client_id".to_string());
+
+        let fetch = |country: &str, offset: u32, _limit: u32| {
+            let ids: &[u64] = match country {
+                "US" => &[1, 2],
+                _ => &[2, 3],
+            };
+            let mut page = list(ids.iter().map(|&id| Item { id: TrackId(id) }).collect());
+            page.offset = offset as usize;
+            std::future::ready(Ok(page))
+        };
+        let items = client
+            .fetch_all_each(vec!["US", "GB"], fetch, |item| item.id)
+            .await
+            .unwrap();
+
+        assert_eq!(ids(&items), [1, 2, 3]);
+    }
+}
@@ -0,0 +1,147 @@
+use crate::Error;
+use crate::Resource;
+use crate::TidalClient;
+use crate::id::ResourceId;
+
+pub(crate) static TIDAL_SHARE_BASE_URL: &str = "https://tidal.com/browse";
+
+impl ResourceId {
+    /// Parse a Tidal web or app link into a typed resource identifier.
+    ///
+    /// Supported formats include browse links, web player links, the URLs
+    /// returned in the `url` field of models, and `tidal://` app links:
+    ///
+    /// - `https://tidal.com/browse/track/123`
+    /// - `https://listen.tidal.com/album/456`
+    /// - `http://www.tidal.com/artist/789`
+    /// - `tidal://playlist/12345678-1234-1234-1234-123456789abc`
+    ///
+    /// When a link names more than one resource, such as
+    /// `https://tidal.com/browse/album/456/track/123`, the last one wins.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidTidalUrl` if the URL is not a Tidal link or
+    /// doesn't contain a recognizable resource.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tidalrs::{ResourceId, TrackId};
+    ///
+    /// let id = ResourceId::from_url("https://tidal.com/browse/track/123").unwrap();
+    /// assert_eq!(id, ResourceId::Track(TrackId(123)));
+    /// ```
+    pub fn from_url(url: &str) -> Result<ResourceId, Error> {
+        let invalid = || Error::InvalidTidalUrl(url.to_string());
+
+        let parsed = url::Url::parse(url.trim()).map_err(|_| invalid())?;
+
+        // For app links the resource type is in the host position (tidal://track/123)
+        let mut segments: Vec<&str> = Vec::new();
+        match parsed.scheme() {
+            "tidal" => {
+                segments.extend(parsed.host_str());
+            }
+            "http" | "https" => {
+                let host = parsed.host_str().ok_or_else(invalid)?;
+                if host != "tidal.com" && !host.ends_with(".tidal.com") {
+                    return Err(invalid());
+                }
+            }
+            _ => return Err(invalid()),
+        }
+        segments.extend(parsed.path_segments().into_iter().flatten());
+
+        let mut resource_id = None;
+        for pair in segments.windows(2) {
+            let id = pair[1];
+            let parsed_id = match pair[0] {
+                "track" | "tracks" => id.parse().ok().map(ResourceId::Track),
+                "album" | "albums" => id.parse().ok().map(ResourceId::Album),
+                "artist" | "artists" => id.parse().ok().map(ResourceId::Artist),
+                "video" | "videos" => id.parse().ok().map(ResourceId::Video),
+                "user" | "profile" => id.parse().ok().map(ResourceId::UserProfile),
+                "playlist" | "playlists" if !id.is_empty() => {
+                    id.parse().ok().map(ResourceId::Playlist)
+                }
+                _ => None,
+            };
+            if parsed_id.is_some() {
+                resource_id = parsed_id;
+            }
+        }
+
+        resource_id.ok_or_else(invalid)
+    }
+
+    /// Generate the canonical Tidal share URL for this resource.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tidalrs::{AlbumId, ResourceId};
+    ///
+    /// let url = ResourceId::Album(AlbumId(456)).share_url();
+    /// assert_eq!(url, "https://tidal.com/browse/album/456");
+    /// ```
+    pub fn share_url(&self) -> String {
+        let path = match self {
+            ResourceId::Artist(_) => "artist",
+            ResourceId::Album(_) => "album",
+            ResourceId::Track(_) => "track",
+            ResourceId::Video(_) => "video",
+            ResourceId::Playlist(_) => "playlist",
+            ResourceId::UserProfile(_) => "profile",
+        };
+        format!("{TIDAL_SHARE_BASE_URL}/{path}/{self}")
+    }
+}
+
+impl TidalClient {
+    /// Fetch the resource that a Tidal link points to.
+    ///
+    /// The link is parsed with `ResourceId::from_url` and the matching track,
+    /// album, artist, playlist, video or user profile is fetched from the API.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - A Tidal web, share or app link
+    ///
+    /// # Returns
+    ///
+    /// Returns the fetched content wrapped in a `Resource`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidTidalUrl` if the link can't be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let resource = client.resolve("https://listen.tidal.com/album/123456789").await?;
+    /// if let tidalrs::Resource::Albums(album) = resource {
+    ///     println!("Album: {}", album.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve(&self, url: &str) -> Result<Resource, Error> {
+        let resource = match ResourceId::from_url(url)? {
+            ResourceId::Track(track_id) => Resource::Tracks(self.track(track_id).await?),
+            ResourceId::Album(album_id) => Resource::Albums(self.album(album_id).await?),
+            ResourceId::Artist(artist_id) => Resource::Artists(self.artist(artist_id).await?),
+            ResourceId::Playlist(playlist_id) => {
+                Resource::Playlists(self.playlist(playlist_id).await?)
+            }
+            ResourceId::Video(video_id) => Resource::Videos(self.video(video_id).await?),
+            ResourceId::UserProfile(user_id) => {
+                Resource::UserProfiles(self.user_profile(user_id).await?)
+            }
+        };
+
+        Ok(resource)
+    }
+}
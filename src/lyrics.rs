@@ -0,0 +1,210 @@
+use crate::Error;
+#[cfg(feature = "extra-fields")]
+use crate::ExtraFields;
+use crate::TIDAL_API_BASE_URL;
+use crate::TidalClient;
+use crate::id::TrackId;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::time::Duration;
+
+/// Lyrics of a track, as plain text and optionally time-synced.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Lyrics {
+    /// Track the lyrics belong to
+    pub track_id: TrackId,
+    /// Provider of the lyrics (e.g., "MusixMatch")
+    #[serde(default)]
+    pub lyrics_provider: String,
+    /// Copyright notice for the lyrics, when the provider supplies one
+    #[serde(default)]
+    pub copyright: Option<String>,
+    /// Plain lyrics, one line per line of the song
+    #[serde(default)]
+    pub lyrics: Option<String>,
+    /// Time-synced lyrics in LRC format
+    ///
+    /// Use synced_lines() to get them parsed
+    #[serde(default)]
+    pub subtitles: Option<String>,
+    /// Whether the lyrics are written right to left
+    #[serde(default)]
+    pub is_right_to_left: bool,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl Lyrics {
+    /// Parse the time-synced lyrics into lines.
+    ///
+    /// # Returns
+    ///
+    /// Returns the lines ordered by time, or an empty list if the track
+    /// has no synced lyrics.
+    pub fn synced_lines(&self) -> Vec<LyricLine> {
+        self.subtitles.as_deref().map(parse_lrc).unwrap_or_default()
+    }
+}
+
+/// A single line of time-synced lyrics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LyricLine {
+    /// When the line starts, from the beginning of the track
+    pub time: Duration,
+    /// Text of the line, which is empty for instrumental breaks
+    pub text: String,
+}
+
+/// Parse lyrics in LRC format.
+///
+/// Lines may carry several timestamps, in which case they're repeated at each
+/// of them. Timestamps can have up to millisecond precision. Metadata tags such
+/// as `[ar:Artist]` and lines without a timestamp are skipped.
+///
+/// # Returns
+///
+/// Returns the lines ordered by time.
+///
+/// # Example
+///
+/// ```
+/// let lines = tidalrs::parse_lrc("[ti:Creep]\n[00:12.50]When you were here before\n");
+/// assert_eq!(lines[0].time, std::time::Duration::from_millis(12_500));
+/// assert_eq!(lines[0].text, "When you were here before");
+/// ```
+pub fn parse_lrc(lrc: &str) -> Vec<LyricLine> {
+    let mut lines = Vec::new();
+
+    for line in lrc.lines() {
+        let mut rest = line.trim();
+        let mut times = Vec::new();
+
+        while let Some(tag) = rest.strip_prefix('[') {
+            let Some((tag, after)) = tag.split_once(']') else {
+                break;
+            };
+            let Some(time) = parse_lrc_time(tag) else {
+                break;
+            };
+            times.push(time);
+            rest = after;
+        }
+
+        let text = rest.trim();
+        lines.extend(times.into_iter().map(|time| LyricLine {
+            time,
+            text: text.to_string(),
+        }));
+    }
+
+    lines.sort_by_key(|line| line.time);
+    lines
+}
+
+/// Write lines of lyrics in LRC format.
+///
+/// Timestamps are written as `[mm:ss.xx]`, with minutes going past 99 for very
+/// long tracks.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use tidalrs::LyricLine;
+///
+/// let lrc = tidalrs::write_lrc(&[LyricLine {
+///     time: Duration::from_millis(72_340),
+///     text: "But I'm a creep".to_string(),
+/// }]);
+/// assert_eq!(lrc, "[01:12.34]But I'm a creep\n");
+/// ```
+pub fn write_lrc(lines: &[LyricLine]) -> String {
+    let mut lrc = String::new();
+
+    for line in lines {
+        let centis = line.time.as_millis() / 10;
+        let _ = writeln!(
+            lrc,
+            "[{:02}:{:02}.{:02}]{}",
+            centis / 6000,
+            centis / 100 % 60,
+            centis % 100,
+            line.text
+        );
+    }
+
+    lrc
+}
+
+// Parse an LRC timestamp such as 01:12.34, 01:12.340, 01:12:34 or 01:12
+fn parse_lrc_time(tag: &str) -> Option<Duration> {
+    let (minutes, seconds) = tag.split_once(':')?;
+    let (seconds, fraction) = match seconds.split_once(['.', ':']) {
+        Some((seconds, fraction)) => (seconds, fraction),
+        None => (seconds, ""),
+    };
+
+    let minutes: u64 = minutes.trim().parse().ok()?;
+    let seconds: u64 = seconds.parse().ok()?;
+    if seconds >= 60 || fraction.len() > 3 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    // Pad the fraction to milliseconds, so .5, .50 and .500 are all 500ms
+    let millis = format!("{fraction:0<3}").parse::<u64>().ok()?;
+    Some(Duration::from_millis(
+        (minutes * 60 + seconds) * 1000 + millis,
+    ))
+}
+
+impl TidalClient {
+    /// Get the lyrics of a track.
+    ///
+    /// # Arguments
+    ///
+    /// * `track_id` - The unique identifier of the track
+    ///
+    /// # Returns
+    ///
+    /// Returns the plain and time-synced lyrics with their provider, or
+    /// `Error::LyricsNotFound` if Tidal has no lyrics for the track.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// match client.track_lyrics(123456789).await {
+    ///     Ok(lyrics) => {
+    ///         for line in lyrics.synced_lines() {
+    ///             println!("{:?} {}", line.time, line.text);
+    ///         }
+    ///     }
+    ///     Err(tidalrs::Error::LyricsNotFound(_)) => println!("No lyrics"),
+    ///     Err(err) => return Err(err.into()),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn track_lyrics(&self, track_id: impl Into<TrackId>) -> Result<Lyrics, Error> {
+        let track_id: TrackId = track_id.into();
+        let url = format!("{TIDAL_API_BASE_URL}/tracks/{track_id}/lyrics");
+        let params = serde_json::json!({
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        self.do_request(Method::GET, &url, Some(params), None)
+            .await
+            .map_err(|err| match err {
+                Error::TidalApiError(err) if err.status == 404 => Error::LyricsNotFound(track_id),
+                err => err,
+            })
+    }
+}
@@ -0,0 +1,243 @@
+use crate::id::{AlbumId, ArtistId, PlaylistId, ResourceId, TrackId, VideoId};
+
+const LINK_OPEN: &str = "[wimpLink ";
+const LINK_CLOSE: &str = "[/wimpLink]";
+
+/// A piece of text that may contain Tidal's inline link markup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkupSegment {
+    /// Plain text
+    Text(String),
+    /// Text linking to a Tidal resource
+    Link {
+        /// The linked resource
+        target: ResourceId,
+        /// The text of the link
+        text: String,
+    },
+}
+
+/// Parse text containing Tidal's inline link markup into segments.
+///
+/// Album reviews and artist bios link to other artists, albums and tracks with
+/// tags such as `[wimpLink artistId="3634"]Portishead[/wimpLink]`. Each tag
+/// becomes a `MarkupSegment::Link`, and the text around it `MarkupSegment::Text`.
+/// Links to anything other than an artist, album, track, video or playlist are
+/// kept as plain text, as are unterminated tags. The `<br/>` tags bios use for
+/// line breaks become newlines, whatever their case and also within link text.
+///
+/// # Example
+///
+/// ```
+/// use tidalrs::{ArtistId, MarkupSegment, ResourceId, parse_markup};
+///
+/// let segments = parse_markup(r#"Produced by [wimpLink artistId="3545"]Nigel Godrich[/wimpLink]."#);
+/// assert_eq!(
+///     segments[1],
+///     MarkupSegment::Link {
+///         target: ResourceId::Artist(ArtistId(3545)),
+///         text: "Nigel Godrich".to_string(),
+///     }
+/// );
+/// ```
+pub fn parse_markup(text: &str) -> Vec<MarkupSegment> {
+    let mut segments = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find(LINK_OPEN) {
+        let tag = &rest[start + LINK_OPEN.len()..];
+        let Some((attributes, after)) = tag.split_once(']') else {
+            break;
+        };
+        let Some((link_text, after)) = after.split_once(LINK_CLOSE) else {
+            break;
+        };
+
+        push_text(&mut segments, &rest[..start]);
+        match parse_link_target(attributes) {
+            Some(target) => segments.push(MarkupSegment::Link {
+                target,
+                text: replace_line_breaks(link_text),
+            }),
+            None => push_text(&mut segments, link_text),
+        }
+        rest = after;
+    }
+
+    push_text(&mut segments, rest);
+    segments
+}
+
+// Append text, merging it into a preceding text segment
+fn push_text(segments: &mut Vec<MarkupSegment>, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    let text = replace_line_breaks(text);
+    match segments.last_mut() {
+        Some(MarkupSegment::Text(previous)) => previous.push_str(&text),
+        _ => segments.push(MarkupSegment::Text(text)),
+    }
+}
+
+// Replace HTML line breaks (<br>, <br/>, <br /> and so on, in any case) with newlines
+fn replace_line_breaks(text: &str) -> String {
+    // Lowercasing ASCII keeps every byte offset the same, so tags are found in the
+    // lowercased text and the original text is copied around them
+    let lower = text.to_ascii_lowercase();
+    let mut replaced = String::with_capacity(text.len());
+    let mut copied = 0;
+    let mut search = 0;
+
+    while let Some(found) = lower[search..].find("<br") {
+        let start = search + found;
+        let tag = lower[start + 3..].trim_start();
+        let tag = tag.strip_prefix('/').unwrap_or(tag).trim_start();
+        match tag.strip_prefix('>') {
+            Some(after) => {
+                replaced.push_str(&text[copied..start]);
+                replaced.push('\n');
+                copied = lower.len() - after.len();
+                search = copied;
+            }
+            None => search = start + 3,
+        }
+    }
+
+    replaced.push_str(&text[copied..]);
+    replaced
+}
+
+// Parse the attributes of a link tag, e.g. artistId="3634"
+fn parse_link_target(attributes: &str) -> Option<ResourceId> {
+    let (name, value) = attributes.trim().split_once('=')?;
+    let value = value.trim().trim_matches('"');
+
+    match name.trim() {
+        "artistId" => value.parse::<ArtistId>().ok().map(ResourceId::Artist),
+        "albumId" => value.parse::<AlbumId>().ok().map(ResourceId::Album),
+        "trackId" => value.parse::<TrackId>().ok().map(ResourceId::Track),
+        "videoId" => value.parse::<VideoId>().ok().map(ResourceId::Video),
+        "playlistId" | "playlistUuid" if !value.is_empty() => {
+            Some(ResourceId::Playlist(PlaylistId::from(value)))
+        }
+        _ => None,
+    }
+}
+
+/// Render parsed markup as plain text, dropping the links but keeping their text.
+///
+/// # Example
+///
+/// ```
+/// let segments = tidalrs::parse_markup(r#"By [wimpLink artistId="3545"]Nigel Godrich[/wimpLink]"#);
+/// assert_eq!(tidalrs::render_plain_text(&segments), "By Nigel Godrich");
+/// ```
+pub fn render_plain_text(segments: &[MarkupSegment]) -> String {
+    segments
+        .iter()
+        .map(|segment| match segment {
+            MarkupSegment::Text(text) => text.as_str(),
+            MarkupSegment::Link { text, .. } => text.as_str(),
+        })
+        .collect()
+}
+
+/// Render parsed markup as Markdown, linking to each resource's share URL.
+///
+/// Characters with a meaning in Markdown are escaped, and line breaks are
+/// kept as hard line breaks.
+///
+/// # Example
+///
+/// ```
+/// let segments = tidalrs::parse_markup(r#"By [wimpLink artistId="3545"]Nigel Godrich[/wimpLink]"#);
+/// assert_eq!(
+///     tidalrs::render_markdown(&segments),
+///     "By [Nigel Godrich](https://tidal.com/browse/artist/3545)"
+/// );
+/// ```
+pub fn render_markdown(segments: &[MarkupSegment]) -> String {
+    let mut markdown = String::new();
+
+    for segment in segments {
+        match segment {
+            MarkupSegment::Text(text) => markdown.push_str(&escape_markdown(text)),
+            MarkupSegment::Link { target, text } => {
+                markdown.push('[');
+                markdown.push_str(&escape_markdown(text));
+                markdown.push_str("](");
+                markdown.push_str(&target.share_url());
+                markdown.push(')');
+            }
+        }
+    }
+
+    markdown
+}
+
+/// Render parsed markup as HTML, linking to each resource's share URL.
+///
+/// Text is escaped, line breaks become `<br>` tags, and each link carries a
+/// `data-tidal-id` attribute with the id of the linked resource.
+///
+/// # Example
+///
+/// ```
+/// let segments = tidalrs::parse_markup(r#"By [wimpLink artistId="3545"]Nigel Godrich[/wimpLink]"#);
+/// assert_eq!(
+///     tidalrs::render_html(&segments),
+///     r#"By <a href="https://tidal.com/browse/artist/3545" data-tidal-id="3545">Nigel Godrich</a>"#
+/// );
+/// ```
+pub fn render_html(segments: &[MarkupSegment]) -> String {
+    let mut html = String::new();
+
+    for segment in segments {
+        match segment {
+            MarkupSegment::Text(text) => html.push_str(&escape_html(text)),
+            MarkupSegment::Link { target, text } => {
+                html.push_str("<a href=\"");
+                html.push_str(&escape_html(&target.share_url()));
+                html.push_str("\" data-tidal-id=\"");
+                html.push_str(&escape_html(&target.to_string()));
+                html.push_str("\">");
+                html.push_str(&escape_html(text));
+                html.push_str("</a>");
+            }
+        }
+    }
+
+    html
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("  \n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '\n' => escaped.push_str("<br>\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
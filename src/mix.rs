@@ -0,0 +1,390 @@
+use crate::CursorList;
+use crate::DEFAULT_STREAM_PAGE_SIZE;
+use crate::Error;
+#[cfg(feature = "extra-fields")]
+use crate::ExtraFields;
+use crate::List;
+use crate::TIDAL_API_BASE_URL;
+use crate::TIDAL_API_V2_BASE_URL;
+use crate::TidalClient;
+use crate::album::AlbumItem;
+use crate::id::{MixId, TrackId};
+use crate::link::TIDAL_SHARE_BASE_URL;
+use crate::paginate;
+use futures::Stream;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Key of the artist radio in `Artist.mixes`.
+pub const ARTIST_MIX: &str = "ARTIST_MIX";
+/// Key of the track radio in `Track.mixes`.
+pub const TRACK_MIX: &str = "TRACK_MIX";
+
+/// A mix is a generated playlist of tracks and videos, such as an artist
+/// radio, a track radio or one of the user's personal mixes.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Mix {
+    /// Unique mix identifier
+    pub id: MixId,
+    /// Mix title
+    pub title: String,
+    /// Mix subtitle, usually naming the artists featured in the mix
+    #[serde(default)]
+    pub sub_title: String,
+    /// Kind of mix (e.g., "ARTIST_MIX", "TRACK_MIX", "DISCOVERY_MIX")
+    #[serde(alias = "type")]
+    pub mix_type: Option<String>,
+    /// Mix artwork in several sizes, keyed by size name ("SMALL", "MEDIUM", "LARGE")
+    #[serde(default)]
+    pub images: HashMap<String, MixImage>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A single size of a mix's artwork.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct MixImage {
+    /// Width of the image in pixels
+    pub width: u32,
+    /// Height of the image in pixels
+    pub height: u32,
+    /// Full URL of the image
+    pub url: String,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// An item in a mix, which is either a track or a video.
+pub type MixItem = AlbumItem;
+
+impl Mix {
+    /// Get the URL of the largest available image for the mix.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(&str)` with the full URL if the mix has any images,
+    /// or `None` otherwise.
+    pub fn image_url(&self) -> Option<&str> {
+        self.images
+            .values()
+            .max_by_key(|image| image.width * image.height)
+            .map(|image| image.url.as_str())
+    }
+
+    /// Generate the canonical Tidal share URL for this mix.
+    pub fn share_url(&self) -> String {
+        format!("{TIDAL_SHARE_BASE_URL}/mix/{}", self.id)
+    }
+}
+
+impl TidalClient {
+    /// Get information about a mix by ID.
+    ///
+    /// Mix ids are found in `Artist.mixes` and `Track.mixes`, or returned by
+    /// `track_mix_id` and `favorite_mixes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `mix_id` - The unique identifier of the mix
+    ///
+    /// # Returns
+    ///
+    /// Returns the `Mix` with its title and artwork. Use `mix_items` to get its contents.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let artist = client.artist(123456789).await?;
+    /// if let Some(mix_id) = artist.radio_mix_id() {
+    ///     let mix = client.mix(mix_id).await?;
+    ///     println!("Mix: {} - {}", mix.title, mix.sub_title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn mix(&self, mix_id: impl Into<MixId>) -> Result<Mix, Error> {
+        let mix_id: MixId = mix_id.into();
+        let url = format!("{TIDAL_API_BASE_URL}/pages/mix");
+        let params = serde_json::json!({
+            "mixId": mix_id,
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let page: Value = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        // The mix itself is in the header module of the mix page
+        let mix = page["rows"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|row| row["modules"].as_array())
+            .flatten()
+            .find_map(|module| module.get("mix"))
+            .ok_or(Error::MixNotFound(mix_id))?;
+
+        Ok(serde_json::from_value(mix.clone())?)
+    }
+
+    /// Get the tracks and videos in a mix with pagination support.
+    ///
+    /// # Arguments
+    ///
+    /// * `mix_id` - The unique identifier of the mix
+    /// * `offset` - Number of items to skip (default: 0)
+    /// * `limit` - Maximum number of items to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of tracks and videos in mix order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let items = client.mix_items("0009a57ac9b5a40d812c7f51b0f92f", None, None).await?;
+    /// for item in items.items {
+    ///     match item {
+    ///         tidalrs::MixItem::Track(track) => println!("Track: {}", track.title),
+    ///         tidalrs::MixItem::Video(video) => println!("Video: {}", video.title),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn mix_items(
+        &self,
+        mix_id: impl Into<MixId>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<MixItem>, Error> {
+        let mix_id: MixId = mix_id.into();
+        let offset = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(100);
+
+        let url = format!("{TIDAL_API_BASE_URL}/mixes/{mix_id}/items");
+
+        let params = serde_json::json!({
+            "offset": offset,
+            "limit": limit,
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: List<MixItem> = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+
+    /// Stream all tracks and videos in a mix.
+    ///
+    /// Pages through the mix on demand, fetching the next page only once the
+    /// previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `mix_id` - The unique identifier of the mix
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut items = std::pin::pin!(client.mix_items_stream("0009a57ac9b5a40d812c7f51b0f92f"));
+    /// while let Some(item) = items.try_next().await? {
+    ///     println!("{:?}", item);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn mix_items_stream(
+        &self,
+        mix_id: impl Into<MixId>,
+    ) -> impl Stream<Item = Result<MixItem, Error>> + '_ {
+        let mix_id: MixId = mix_id.into();
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.mix_items(mix_id.clone(), Some(offset), Some(limit))
+        })
+    }
+
+    /// Look up the id of a track's radio mix.
+    ///
+    /// Tracks returned by most endpoints already carry this in `Track.mixes`,
+    /// this is for when only the track id is known.
+    ///
+    /// # Arguments
+    ///
+    /// * `track_id` - The unique identifier of the track
+    ///
+    /// # Returns
+    ///
+    /// Returns the `MixId` of the track radio, which can be passed to `mix` and `mix_items`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let mix_id = client.track_mix_id(123456789).await?;
+    /// let radio = client.mix_items(mix_id, None, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn track_mix_id(&self, track_id: impl Into<TrackId>) -> Result<MixId, Error> {
+        let track_id: TrackId = track_id.into();
+        let url = format!("{TIDAL_API_BASE_URL}/tracks/{track_id}/mix");
+        let params = serde_json::json!({
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        #[derive(Deserialize)]
+        struct TrackMix {
+            id: MixId,
+        }
+
+        let resp: TrackMix = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp.id)
+    }
+
+    /// Get the authenticated user's favorite mixes with cursor pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of mixes to return (default: 50)
+    /// * `cursor` - Cursor returned by the previous page, or `None` for the first page
+    ///
+    /// # Returns
+    ///
+    /// Returns a page of mixes the user has favorited.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let favorites = client.favorite_mixes(None, None).await?;
+    /// for mix in favorites.items {
+    ///     println!("Favorite: {}", mix.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn favorite_mixes(
+        &self,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<CursorList<Mix>, Error> {
+        self.get_user_id()
+            .ok_or(Error::UserAuthenticationRequired)?;
+        let url = format!("{TIDAL_API_V2_BASE_URL}/favorites/mixes");
+
+        let resp: CursorList<Mix> = self
+            .do_request(
+                Method::GET,
+                &url,
+                Some(self.cursor_params(limit, cursor)),
+                None,
+            )
+            .await?;
+
+        Ok(resp)
+    }
+
+    /// Add a mix to the authenticated user's favorites.
+    ///
+    /// # Arguments
+    ///
+    /// * `mix_id` - The unique identifier of the mix to favorite
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// client.add_favorite_mix("0009a57ac9b5a40d812c7f51b0f92f").await?;
+    /// println!("Mix added to favorites!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_favorite_mix(&self, mix_id: impl Into<MixId>) -> Result<(), Error> {
+        let mix_id: MixId = mix_id.into();
+        self.get_user_id()
+            .ok_or(Error::UserAuthenticationRequired)?;
+        let url = format!("{TIDAL_API_V2_BASE_URL}/favorites/mixes/add");
+
+        let params = serde_json::json!({
+            "mixIds": mix_id,
+            "onArtifactNotFound": "FAIL",
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let _: Value = self
+            .do_request(Method::PUT, &url, Some(params), None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Remove a mix from the authenticated user's favorites.
+    ///
+    /// # Arguments
+    ///
+    /// * `mix_id` - The unique identifier of the mix to remove from favorites
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// client.remove_favorite_mix("0009a57ac9b5a40d812c7f51b0f92f").await?;
+    /// println!("Mix removed from favorites!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_favorite_mix(&self, mix_id: impl Into<MixId>) -> Result<(), Error> {
+        let mix_id: MixId = mix_id.into();
+        self.get_user_id()
+            .ok_or(Error::UserAuthenticationRequired)?;
+        let url = format!("{TIDAL_API_V2_BASE_URL}/favorites/mixes/remove");
+
+        let params = serde_json::json!({
+            "mixIds": mix_id,
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let _: Value = self
+            .do_request(Method::PUT, &url, Some(params), None)
+            .await?;
+
+        Ok(())
+    }
+}
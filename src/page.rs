@@ -0,0 +1,611 @@
+use crate::Error;
+#[cfg(feature = "extra-fields")]
+use crate::ExtraFields;
+use crate::List;
+use crate::TIDAL_API_BASE_URL;
+use crate::TidalClient;
+use crate::album::{Album, AlbumItem, AlbumReview};
+use crate::artist::Artist;
+use crate::id::{AlbumId, ArtistId, PlaylistId, ResourceId};
+use crate::mix::Mix;
+use crate::playlist::Playlist;
+use crate::track::Track;
+use crate::video::Video;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+
+/// An editorial page, made up of rows of modules.
+///
+/// Pages are what the Tidal apps render for home, explore, genres, moods, albums
+/// and artists. Each row holds one or more modules, such as a list of albums.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Page {
+    /// Unique page identifier
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Page title
+    #[serde(default)]
+    pub title: String,
+    /// Rows of the page from top to bottom
+    #[serde(default)]
+    pub rows: Vec<PageRow>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl Page {
+    /// Iterate over every module on the page, row by row.
+    pub fn modules(&self) -> impl Iterator<Item = &PageModule> {
+        self.rows.iter().flat_map(|row| &row.modules)
+    }
+}
+
+/// A row of a page, holding its modules side by side.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PageRow {
+    /// Modules in the row
+    #[serde(default)]
+    pub modules: Vec<PageModule>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A module of a page, typed by the kind of content it shows.
+///
+/// Modules of a type this crate doesn't know are kept as `PageModule::Other`
+/// rather than failing the page. A module of a known type that doesn't decode
+/// as that type is an error.
+#[derive(Debug, Serialize, Clone)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PageModule {
+    /// Header of an album page with the album and its review
+    AlbumHeader(Box<AlbumHeaderModule>),
+    /// Header of an artist page with the artist
+    ArtistHeader(Box<ArtistHeaderModule>),
+    /// Tracks and videos of an album
+    AlbumItems(PagedModule<AlbumItem>),
+    /// List of albums
+    AlbumList(PagedModule<Album>),
+    /// List of artists
+    ArtistList(PagedModule<Artist>),
+    /// List of tracks
+    TrackList(PagedModule<Track>),
+    /// List of videos
+    VideoList(PagedModule<Video>),
+    /// List of playlists
+    PlaylistList(PagedModule<Playlist>),
+    /// List of mixes
+    MixList(PagedModule<Mix>),
+    /// Editorial promotions, shown as large banners
+    ///
+    /// Tidal's `MULTIPLE_TOP_PROMOTIONS` modules are read as this type too
+    FeaturedPromotions(PromotionModule),
+    /// Links to other pages, such as genres and moods
+    ///
+    /// Tidal's `PAGE_LINKS_CLOUD` and `PAGE_LINKS_IMAGE` modules are read as this type too
+    PageLinks(PagedModule<PageLink>),
+    /// Any other module, kept as raw JSON
+    #[serde(untagged)]
+    Other(GenericModule),
+}
+
+impl PageModule {
+    /// Get the title of the module, which may be empty.
+    pub fn title(&self) -> &str {
+        match self {
+            PageModule::AlbumHeader(module) => &module.title,
+            PageModule::ArtistHeader(module) => &module.title,
+            PageModule::AlbumItems(module) => &module.title,
+            PageModule::AlbumList(module) => &module.title,
+            PageModule::ArtistList(module) => &module.title,
+            PageModule::TrackList(module) => &module.title,
+            PageModule::VideoList(module) => &module.title,
+            PageModule::PlaylistList(module) => &module.title,
+            PageModule::MixList(module) => &module.title,
+            PageModule::FeaturedPromotions(module) => &module.title,
+            PageModule::PageLinks(module) => &module.title,
+            PageModule::Other(module) => &module.title,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PageModule {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = Value::deserialize(deserializer)?;
+        let module_type = take_module_type(&mut value);
+
+        fn typed<T: DeserializeOwned>(
+            value: &Value,
+            variant: fn(T) -> PageModule,
+        ) -> Option<Result<PageModule, serde_json::Error>> {
+            Some(serde_json::from_value(value.clone()).map(variant))
+        }
+
+        let module = match module_type.as_str() {
+            "ALBUM_HEADER" => typed(&value, |module| PageModule::AlbumHeader(Box::new(module))),
+            "ARTIST_HEADER" => typed(&value, |module| PageModule::ArtistHeader(Box::new(module))),
+            "ALBUM_ITEMS" => typed(&value, PageModule::AlbumItems),
+            "ALBUM_LIST" => typed(&value, PageModule::AlbumList),
+            "ARTIST_LIST" => typed(&value, PageModule::ArtistList),
+            "TRACK_LIST" => typed(&value, PageModule::TrackList),
+            "VIDEO_LIST" => typed(&value, PageModule::VideoList),
+            "PLAYLIST_LIST" => typed(&value, PageModule::PlaylistList),
+            "MIX_LIST" => typed(&value, PageModule::MixList),
+            "FEATURED_PROMOTIONS" | "MULTIPLE_TOP_PROMOTIONS" => {
+                typed(&value, PageModule::FeaturedPromotions)
+            }
+            "PAGE_LINKS" | "PAGE_LINKS_CLOUD" | "PAGE_LINKS_IMAGE" => {
+                typed(&value, PageModule::PageLinks)
+            }
+            _ => None,
+        };
+
+        match module {
+            Some(module) => module.map_err(|e| {
+                serde::de::Error::custom(format!("invalid {module_type} module: {e}"))
+            }),
+            None => Ok(PageModule::Other(GenericModule::new(module_type, value))),
+        }
+    }
+}
+
+/// A module showing a page of items, with a link to the rest.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase", bound(deserialize = "T: DeserializeOwned"))]
+pub struct PagedModule<T> {
+    /// Unique module identifier
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Module title (e.g., "More Albums by Radiohead")
+    #[serde(default)]
+    pub title: String,
+    /// Module description, which may be empty
+    #[serde(default)]
+    pub description: String,
+    /// The first page of items
+    pub paged_list: List<T>,
+    /// Link to the full list, if there are more items than shown
+    #[serde(default)]
+    pub show_more: Option<ShowMore>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A link from a module to a page with all of its items.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ShowMore {
+    /// Text of the link (e.g., "View all")
+    pub title: String,
+    /// API path of the page the link leads to
+    pub api_path: String,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// The header of an album page.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AlbumHeaderModule {
+    /// Unique module identifier
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Module title, which is usually empty
+    #[serde(default)]
+    pub title: String,
+    /// The album the page is about
+    pub album: Album,
+    /// Editorial review of the album, if there is one
+    #[serde(default)]
+    pub review: Option<AlbumReview>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// The header of an artist page.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtistHeaderModule {
+    /// Unique module identifier
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Module title, which is usually empty
+    #[serde(default)]
+    pub title: String,
+    /// The artist the page is about
+    pub artist: Artist,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A module of editorial promotions.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PromotionModule {
+    /// Unique module identifier
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Module title, which may be empty
+    #[serde(default)]
+    pub title: String,
+    /// The promotions, in display order
+    #[serde(default)]
+    pub items: Vec<Promotion>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// An editorial promotion of an album, playlist, artist or other content.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Promotion {
+    /// Promotion title
+    #[serde(default)]
+    pub title: String,
+    /// Short header shown above the title (e.g., "New Album")
+    #[serde(default)]
+    pub short_header: String,
+    /// Short line shown below the header (e.g., the artist name)
+    #[serde(default)]
+    pub short_sub_header: String,
+    /// Longer promotional text, which may be empty
+    #[serde(default)]
+    pub text: String,
+    /// Promotion image identifier
+    ///
+    /// Use image_url() to get the full URL of the image
+    pub image_id: Option<String>,
+    /// Type of the promoted content (e.g., "ALBUM", "PLAYLIST", "ARTIST", "EXTURL")
+    #[serde(rename = "type")]
+    pub promoted_type: String,
+    /// Identifier of the promoted content, or a URL for external links
+    pub artifact_id: String,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl Promotion {
+    /// Generate a URL for the promotion's image at the specified dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - Height of the image in pixels
+    /// * `width` - Width of the image in pixels
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(String)` with the full URL if an image is available,
+    /// or `None` if no image is set.
+    pub fn image_url(&self, height: u16, width: u16) -> Option<String> {
+        self.image_id.as_ref().map(|image_id| {
+            let image_path = image_id.replace('-', "/");
+            format!("https://resources.tidal.com/images/{image_path}/{height}x{width}.jpg")
+        })
+    }
+
+    /// Get the id of the promoted content, if it is an artist, album, track,
+    /// video or playlist.
+    pub fn target(&self) -> Option<ResourceId> {
+        let id = self.artifact_id.as_str();
+        match self.promoted_type.as_str() {
+            "ARTIST" => id.parse().ok().map(ResourceId::Artist),
+            "ALBUM" => id.parse().ok().map(ResourceId::Album),
+            "TRACK" => id.parse().ok().map(ResourceId::Track),
+            "VIDEO" => id.parse().ok().map(ResourceId::Video),
+            "PLAYLIST" => Some(ResourceId::Playlist(PlaylistId::from(id))),
+            _ => None,
+        }
+    }
+}
+
+/// A link to another page, such as a genre or mood.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PageLink {
+    /// Link title (e.g., "Hip-Hop")
+    pub title: String,
+    /// API path of the linked page, which can be passed to `page`
+    pub api_path: String,
+    /// Icon of the link, which may be empty
+    #[serde(default)]
+    pub icon: String,
+    /// Link image identifier
+    #[serde(default)]
+    pub image_id: Option<String>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A module of a type that isn't modelled by `PageModule`.
+///
+/// The module's JSON is kept in `raw` and written back out, with its type,
+/// when the module is serialized.
+#[derive(Debug, Clone)]
+pub struct GenericModule {
+    /// Type of the module as sent by Tidal (e.g., "SOCIAL")
+    pub module_type: String,
+    /// Module title, which may be empty
+    pub title: String,
+    /// The module's JSON, without its type
+    pub raw: Value,
+}
+
+impl GenericModule {
+    // Keep a module's JSON as it was sent, apart from its type
+    fn new(module_type: String, raw: Value) -> Self {
+        GenericModule {
+            title: raw["title"].as_str().unwrap_or_default().to_string(),
+            module_type,
+            raw,
+        }
+    }
+}
+
+impl Serialize for GenericModule {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut module = match &self.raw {
+            Value::Object(module) => module.clone(),
+            _ => Map::new(),
+        };
+        module.insert("type".to_string(), Value::String(self.module_type.clone()));
+        module.insert("title".to_string(), Value::String(self.title.clone()));
+        module.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GenericModule {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut value = Value::deserialize(deserializer)?;
+        let module_type = take_module_type(&mut value);
+        Ok(GenericModule::new(module_type, value))
+    }
+}
+
+// Remove a module's type from its JSON, so the rest can be decoded as the
+// module it names
+fn take_module_type(value: &mut Value) -> String {
+    match value
+        .as_object_mut()
+        .and_then(|module| module.remove("type"))
+    {
+        Some(Value::String(module_type)) => module_type,
+        _ => String::new(),
+    }
+}
+
+impl TidalClient {
+    /// Get a page by its API path.
+    ///
+    /// API paths come from `PageLink::api_path` and `ShowMore::api_path`, and
+    /// may be given with or without their leading `pages/`.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_path` - API path of the page (e.g., "pages/genre_hip_hop")
+    ///
+    /// # Returns
+    ///
+    /// Returns the page as rows of typed modules.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::PageModule;
+    ///
+    /// let explore = client.explore_page().await?;
+    /// for module in explore.modules() {
+    ///     if let PageModule::PageLinks(links) = module {
+    ///         let first = client.page(&links.paged_list.items[0].api_path).await?;
+    ///         println!("{}", first.title);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn page(&self, api_path: &str) -> Result<Page, Error> {
+        let path = api_path.trim_start_matches('/');
+        let path = path.strip_prefix("pages/").unwrap_or(path);
+        self.fetch_page(path, serde_json::json!({})).await
+    }
+
+    /// Get the home page, with the user's personal mixes and editorial picks.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let home = client.home_page().await?;
+    /// for module in home.modules() {
+    ///     println!("{}", module.title());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn home_page(&self) -> Result<Page, Error> {
+        self.fetch_page("home", serde_json::json!({})).await
+    }
+
+    /// Get the explore page, with links to genres, moods and other sections.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let explore = client.explore_page().await?;
+    /// println!("{} modules", explore.modules().count());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn explore_page(&self) -> Result<Page, Error> {
+        self.fetch_page("explore", serde_json::json!({})).await
+    }
+
+    /// Get the page of a genre.
+    ///
+    /// # Arguments
+    ///
+    /// * `genre` - Name of the genre as used in page paths (e.g., "hip_hop"),
+    ///   with or without the `genre_` prefix
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let page = client.genre_page("hip_hop").await?;
+    /// println!("{}", page.title);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn genre_page(&self, genre: &str) -> Result<Page, Error> {
+        self.fetch_page(&prefixed_page_path("genre_", genre), serde_json::json!({}))
+            .await
+    }
+
+    /// Get the page of a mood, such as "relax" or "party".
+    ///
+    /// # Arguments
+    ///
+    /// * `mood` - Name of the mood as used in page paths (e.g., "relax"),
+    ///   with or without the `mood_` prefix
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let page = client.mood_page("relax").await?;
+    /// println!("{}", page.title);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn mood_page(&self, mood: &str) -> Result<Page, Error> {
+        self.fetch_page(&prefixed_page_path("mood_", mood), serde_json::json!({}))
+            .await
+    }
+
+    /// Get the editorial page of an artist.
+    ///
+    /// The page has the artist header, their top tracks, albums, videos and
+    /// modules such as related artists.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::PageModule;
+    ///
+    /// let page = client.artist_page(123456789).await?;
+    /// for module in page.modules() {
+    ///     if let PageModule::TrackList(tracks) = module {
+    ///         println!("{}: {} tracks", tracks.title, tracks.paged_list.total);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn artist_page(&self, artist_id: impl Into<ArtistId>) -> Result<Page, Error> {
+        let artist_id: ArtistId = artist_id.into();
+        self.fetch_page("artist", serde_json::json!({ "artistId": artist_id }))
+            .await
+    }
+
+    /// Get the editorial page of an album.
+    ///
+    /// The page has the album header with its review, the album's items, and
+    /// modules such as more albums by the artist and related artists.
+    ///
+    /// # Arguments
+    ///
+    /// * `album_id` - The unique identifier of the album
+    ///
+    /// # Returns
+    ///
+    /// Returns the album's page as rows of typed modules.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::PageModule;
+    ///
+    /// let page = client.album_page(123456789).await?;
+    /// for module in page.modules() {
+    ///     if let PageModule::AlbumList(albums) = module {
+    ///         println!("{}: {} albums", albums.title, albums.paged_list.total);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn album_page(&self, album_id: impl Into<AlbumId>) -> Result<Page, Error> {
+        let album_id: AlbumId = album_id.into();
+        self.fetch_page("album", serde_json::json!({ "albumId": album_id }))
+            .await
+    }
+
+    // Fetch the page at /pages/{path}, with params identifying what the page is about
+    async fn fetch_page(&self, path: &str, mut params: Value) -> Result<Page, Error> {
+        let url = format!("{TIDAL_API_BASE_URL}/pages/{path}");
+        params["countryCode"] = self.get_country_code().into();
+        params["locale"] = self.get_locale().into();
+        params["deviceType"] = self.get_device_type().as_ref().into();
+
+        let resp: Page = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+}
+
+// Build a genre or mood page path, e.g. "Hip Hop" becomes "genre_hip_hop"
+fn prefixed_page_path(prefix: &str, name: &str) -> String {
+    let name = name.trim().to_lowercase().replace([' ', '-'], "_");
+    if name.starts_with(prefix) {
+        name
+    } else {
+        format!("{prefix}{name}")
+    }
+}
@@ -1,13 +1,24 @@
 use crate::deserialize_null_default;
+use crate::DEFAULT_STREAM_PAGE_SIZE;
 use crate::Error;
+#[cfg(feature = "extra-fields")]
+use crate::ExtraFields;
 use crate::List;
+use crate::Order;
+use crate::OrderDirection;
 use crate::TIDAL_API_BASE_URL;
 use crate::TidalClient;
+use crate::Timestamp;
 use crate::artist::ArtistSummary;
+use crate::id::{PlaylistId, ResourceId, TrackId, UserId};
+use crate::paginate;
+use crate::paginate_positioned;
 use crate::track::Track;
+use futures::{Stream, TryStreamExt};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashSet;
 
 /// Represents a playlist from the Tidal catalog.
 ///
@@ -17,7 +28,7 @@ use serde_json::Value;
 #[serde(rename_all = "camelCase")]
 pub struct Playlist {
     /// Unique playlist identifier (UUID format)
-    pub uuid: String,
+    pub uuid: PlaylistId,
     /// Playlist title
     pub title: String,
     /// Tidal URL for the playlist
@@ -39,11 +50,17 @@ pub struct Playlist {
     pub popularity: u32,
 
     /// ISO timestamp when the playlist was last updated
-    pub last_updated: String,
+    #[cfg_attr(feature = "chrono", serde(with = "crate::datetime::timestamp"))]
+    pub last_updated: Timestamp,
     /// ISO timestamp when the playlist was created
-    pub created: String,
+    #[cfg_attr(feature = "chrono", serde(with = "crate::datetime::timestamp"))]
+    pub created: Timestamp,
     /// ISO timestamp when the last item was added to the playlist
-    pub last_item_added_at: Option<String>,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::datetime::option_timestamp")
+    )]
+    pub last_item_added_at: Option<Timestamp>,
 
     /// Type of playlist (e.g., "USER", "EDITORIAL")
     #[serde(rename = "type")]
@@ -67,6 +84,11 @@ pub struct Playlist {
     ///
     /// This is needed for adding or removing tracks from the playlist
     pub etag: Option<String>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl Playlist {
@@ -104,6 +126,11 @@ impl Playlist {
             format!("https://resources.tidal.com/images/{square_image_path}/{size}x{size}.jpg")
         })
     }
+
+    /// Generate the canonical Tidal share URL for this playlist.
+    pub fn share_url(&self) -> String {
+        ResourceId::Playlist(self.uuid.clone()).share_url()
+    }
 }
 
 /// Information about the creator of a playlist.
@@ -115,7 +142,64 @@ pub struct PlaylistCreator {
     /// The user ID of the playlist creator.
     /// Will be None or zero if the playlist creator is not a known user.
     #[serde(default)]
-    pub id: Option<u64>,
+    pub id: Option<UserId>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Represents a playlist that has been added to a user's favorites.
+///
+/// This structure includes the playlist data along with metadata
+/// about when it was added to favorites.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoritePlaylist {
+    /// ISO timestamp when the playlist was added to favorites
+    #[cfg_attr(feature = "chrono", serde(with = "crate::datetime::timestamp"))]
+    pub created: Timestamp,
+    /// The playlist data
+    pub item: Playlist,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// How a playlist came to be in the user's collection.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+pub enum UserPlaylistKind {
+    /// The user created the playlist
+    #[serde(rename = "USER_CREATED")]
+    Created,
+    /// The user favorited someone else's playlist
+    #[serde(rename = "USER_FAVORITE")]
+    Favorite,
+}
+
+/// A playlist in the user's collection, either created or favorited by them.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPlaylist {
+    /// Whether the user created or favorited the playlist
+    #[serde(rename = "type")]
+    pub kind: UserPlaylistKind,
+    /// ISO timestamp when the playlist was created or favorited
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::datetime::option_timestamp")
+    )]
+    pub created: Option<Timestamp>,
+    /// The playlist data
+    pub playlist: Playlist,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// A recommended item from a playlist recommendations response.
@@ -147,10 +231,15 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let playlist = client.playlist("12345678-1234-1234-1234-123456789abc").await?;
     /// println!("Playlist: {}", playlist.title);
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn playlist(&self, playlist_id: &str) -> Result<Playlist, Error> {
+    pub async fn playlist(&self, playlist_id: impl Into<PlaylistId>) -> Result<Playlist, Error> {
+        let playlist_id: PlaylistId = playlist_id.into();
         let url = format!("{TIDAL_API_BASE_URL}/playlists/{playlist_id}");
         let params = serde_json::json!({
             "countryCode": self.get_country_code(),
@@ -179,17 +268,22 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let tracks = client.playlist_tracks("12345678-1234-1234-1234-123456789abc", Some(0), Some(20)).await?;
     /// for track in tracks.items {
     ///     println!("Track: {}", track.title);
     /// }
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn playlist_tracks(
         &self,
-        playlist_id: &str,
+        playlist_id: impl Into<PlaylistId>,
         offset: Option<u32>,
         limit: Option<u32>,
     ) -> Result<List<Track>, Error> {
+        let playlist_id: PlaylistId = playlist_id.into();
         let offset = offset.unwrap_or(0);
         let limit = limit.unwrap_or(100);
         let url = format!("{TIDAL_API_BASE_URL}/playlists/{playlist_id}/tracks");
@@ -208,6 +302,79 @@ impl TidalClient {
         Ok(resp)
     }
 
+    /// Stream all tracks in a playlist.
+    ///
+    /// Pages through the playlist's tracks on demand, fetching the next page only
+    /// once the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `playlist_id` - The unique identifier (UUID) of the playlist
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut tracks = std::pin::pin!(client.playlist_tracks_stream("12345678-1234-1234-1234-123456789abc"));
+    /// while let Some(track) = tracks.try_next().await? {
+    ///     println!("Track: {}", track.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn playlist_tracks_stream(
+        &self,
+        playlist_id: impl Into<PlaylistId>,
+    ) -> impl Stream<Item = Result<Track, Error>> + '_ {
+        let playlist_id: PlaylistId = playlist_id.into();
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.playlist_tracks(playlist_id.clone(), Some(offset), Some(limit))
+        })
+    }
+
+    /// Fetch all tracks in a playlist.
+    ///
+    /// The first page is fetched to learn the total, after which the remaining
+    /// pages are fetched concurrently, bounded by the client's `max_concurrency`.
+    /// A track that is on the playlist more than once is returned every time.
+    ///
+    /// # Arguments
+    ///
+    /// * `playlist_id` - The unique identifier (UUID) of the playlist
+    ///
+    /// # Returns
+    ///
+    /// Returns every track in the playlist, in playlist order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let tracks = client.playlist_tracks_all("12345678-1234-1234-1234-123456789abc").await?;
+    /// println!("{} tracks", tracks.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn playlist_tracks_all(
+        &self,
+        playlist_id: impl Into<PlaylistId>,
+    ) -> Result<Vec<Track>, Error> {
+        let playlist_id: PlaylistId = playlist_id.into();
+
+        // A playlist can hold the same track more than once, so repeats are all kept
+        let pages = self
+            .fetch_pages(DEFAULT_STREAM_PAGE_SIZE, |offset, limit| {
+                self.playlist_tracks(&playlist_id, Some(offset), Some(limit))
+            })
+            .await?;
+
+        Ok(pages.into_iter().flatten().collect())
+    }
+
     /// Create a new playlist for the authenticated user.
     ///
     /// # Arguments
@@ -222,8 +389,12 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let playlist = client.create_playlist("My Favorites", "A collection of my favorite songs").await?;
     /// println!("Created playlist: {}", playlist.title);
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn create_playlist(&self, title: &str, description: &str) -> Result<Playlist, Error> {
         let user_id = self
@@ -255,18 +426,23 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let playlist = client.playlist("12345678-1234-1234-1234-123456789abc").await?;
-    /// let track_ids = vec![123456789, 987654321];
+    /// let track_ids = vec![tidalrs::TrackId(123456789), tidalrs::TrackId(987654321)];
     /// client.add_tracks_to_playlist(&playlist.uuid, &playlist.etag.unwrap(), track_ids, false).await?;
     /// println!("Tracks added to playlist!");
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn add_tracks_to_playlist(
         &self,
-        playlist_id: &str,
+        playlist_id: impl Into<PlaylistId>,
         playlist_etag: &str,
-        track_ids: Vec<u64>,
+        track_ids: Vec<TrackId>,
         add_dupes: bool,
     ) -> Result<(), Error> {
+        let playlist_id: PlaylistId = playlist_id.into();
         let url = format!("{TIDAL_API_BASE_URL}/playlists/{playlist_id}/items");
 
         // Convert track IDs to comma-separated string
@@ -304,16 +480,21 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let playlist = client.playlist("12345678-1234-1234-1234-123456789abc").await?;
     /// client.remove_track_from_playlist_by_index(&playlist.uuid, &playlist.etag.unwrap(), 0).await?;
     /// println!("Track removed from playlist!");
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn remove_track_from_playlist_by_index(
         &self,
-        playlist_id: &str,
+        playlist_id: impl Into<PlaylistId>,
         playlist_etag: &str,
         index: usize,
     ) -> Result<(), Error> {
+        let playlist_id: PlaylistId = playlist_id.into();
         let url = format!("{TIDAL_API_BASE_URL}/playlists/{playlist_id}/items/{index}");
 
         let _: Value = self
@@ -342,49 +523,41 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let playlist = client.playlist("12345678-1234-1234-1234-123456789abc").await?;
     /// client.remove_track_from_playlist(&playlist.uuid, &playlist.etag.unwrap(), 123456789).await?;
     /// println!("Track removed from playlist!");
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn remove_track_from_playlist(
         &self,
-        playlist_id: &str,
+        playlist_id: impl Into<PlaylistId>,
         playlist_etag: &str,
-        track_id: u64,
+        track_id: impl Into<TrackId>,
     ) -> Result<(), Error> {
-        // Find the index of the track in the playlist
+        let playlist_id: PlaylistId = playlist_id.into();
+        let track_id: TrackId = track_id.into();
 
-        let track_index: Option<u32>;
-        let mut offset: u32 = 0;
-
-        'outer: loop {
-            let playlist_tracks = self
-                .playlist_tracks(playlist_id, Some(offset), None)
-                .await?;
-
-            for (index, track) in playlist_tracks.items.iter().enumerate() {
-                if track.id == track_id {
-                    track_index = Some(index as u32);
-                    break 'outer;
-                }
-            }
+        // Find the index of the track in the playlist, counting tracks that failed to decode
+        let tracks = paginate_positioned(DEFAULT_STREAM_PAGE_SIZE, |offset, limit| {
+            self.playlist_tracks(playlist_id.clone(), Some(offset), Some(limit))
+        });
+        let mut tracks = std::pin::pin!(tracks);
 
-            if playlist_tracks.num_left() == 0 {
-                return Err(Error::PlaylistTrackNotFound(
-                    playlist_id.to_string(),
-                    track_id,
-                ));
+        let mut track_index = None;
+        while let Some((index, track)) = tracks.try_next().await? {
+            if track.id == track_id {
+                track_index = Some(index);
+                break;
             }
-
-            offset += playlist_tracks.items.len() as u32;
         }
 
-        let track_index = track_index.ok_or(Error::PlaylistTrackNotFound(
-            playlist_id.to_string(),
-            track_id,
-        ))?;
+        let track_index =
+            track_index.ok_or(Error::PlaylistTrackNotFound(playlist_id.clone(), track_id))?;
 
-        self.remove_track_from_playlist_by_index(playlist_id, playlist_etag, track_index as usize)
+        self.remove_track_from_playlist_by_index(&playlist_id, playlist_etag, track_index)
             .await?;
 
         Ok(())
@@ -404,10 +577,14 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let playlists = client.user_playlists(None, Some(10)).await?;
     /// for playlist in playlists.items {
     ///     println!("Playlist: {}", playlist.title);
     /// }
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn user_playlists(
         &self,
@@ -435,6 +612,383 @@ impl TidalClient {
         Ok(resp)
     }
 
+    /// Stream all playlists created by the authenticated user.
+    ///
+    /// Pages through the user's playlists on demand, fetching the next page only
+    /// once the previous one has been consumed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut playlists = std::pin::pin!(client.user_playlists_stream());
+    /// while let Some(playlist) = playlists.try_next().await? {
+    ///     println!("Playlist: {}", playlist.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn user_playlists_stream(&self) -> impl Stream<Item = Result<Playlist, Error>> + '_ {
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.user_playlists(Some(offset), Some(limit))
+        })
+    }
+
+    /// Fetch all playlists created by the authenticated user.
+    ///
+    /// The first page is fetched to learn the total, after which the remaining
+    /// pages are fetched concurrently, bounded by the client's `max_concurrency`.
+    ///
+    /// # Returns
+    ///
+    /// Returns every playlist created by the user.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let playlists = client.user_playlists_all().await?;
+    /// println!("{} playlists", playlists.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_playlists_all(&self) -> Result<Vec<Playlist>, Error> {
+        self.fetch_all(
+            DEFAULT_STREAM_PAGE_SIZE,
+            |offset, limit| self.user_playlists(Some(offset), Some(limit)),
+            |playlist| playlist.uuid.clone(),
+        )
+        .await
+    }
+
+    /// Get the authenticated user's favorite playlists with pagination and sorting.
+    ///
+    /// Only playlists favorited by the user are returned, not those they created.
+    /// Use `user_and_favorite_playlists` to get both.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Number of playlists to skip (default: 0)
+    /// * `limit` - Maximum number of playlists to return (default: 100)
+    /// * `order` - Sort order (default: Date)
+    /// * `order_direction` - Sort direction (default: Desc)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of playlists the user has favorited.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let favorites = client.favorite_playlists(None, Some(10), None, None).await?;
+    /// for favorite in favorites.items {
+    ///     println!("Favorite: {}", favorite.item.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn favorite_playlists(
+        &self,
+        offset: Option<u32>,
+        limit: Option<u32>,
+        order: Option<Order>,
+        order_direction: Option<OrderDirection>,
+    ) -> Result<List<FavoritePlaylist>, Error> {
+        let user_id = self
+            .get_user_id()
+            .ok_or(Error::UserAuthenticationRequired)?;
+        let offset = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(100);
+
+        let url = format!("{TIDAL_API_BASE_URL}/users/{user_id}/favorites/playlists");
+
+        let params = serde_json::json!({
+            "offset": offset,
+            "limit": limit,
+            "order": order.unwrap_or(Order::Date).as_ref(),
+            "orderDirection": order_direction.unwrap_or(OrderDirection::Desc).as_ref(),
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: List<FavoritePlaylist> = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+
+    /// Stream all of the authenticated user's favorite playlists.
+    ///
+    /// Pages through all of the user's favorite playlists on demand, fetching
+    /// the next page only once the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Sort order (default: Date)
+    /// * `order_direction` - Sort direction (default: Desc)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut favorites = std::pin::pin!(client.favorite_playlists_stream(None, None));
+    /// while let Some(favorite) = favorites.try_next().await? {
+    ///     println!("Favorite: {}", favorite.item.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn favorite_playlists_stream(
+        &self,
+        order: Option<Order>,
+        order_direction: Option<OrderDirection>,
+    ) -> impl Stream<Item = Result<FavoritePlaylist, Error>> + '_ {
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.favorite_playlists(Some(offset), Some(limit), order, order_direction)
+        })
+    }
+
+    /// Fetch all of the authenticated user's favorite playlists.
+    ///
+    /// The first page is fetched to learn the total, after which the remaining
+    /// pages are fetched concurrently, bounded by the client's `max_concurrency`.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Sort order (default: Date)
+    /// * `order_direction` - Sort direction (default: Desc)
+    ///
+    /// # Returns
+    ///
+    /// Returns every playlist the user has favorited, in the requested order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let favorites = client.favorite_playlists_all(None, None).await?;
+    /// println!("{} favorite playlists", favorites.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn favorite_playlists_all(
+        &self,
+        order: Option<Order>,
+        order_direction: Option<OrderDirection>,
+    ) -> Result<Vec<FavoritePlaylist>, Error> {
+        self.fetch_all(
+            DEFAULT_STREAM_PAGE_SIZE,
+            |offset, limit| {
+                self.favorite_playlists(Some(offset), Some(limit), order, order_direction)
+            },
+            |favorite| favorite.item.uuid.clone(),
+        )
+        .await
+    }
+
+    /// Add a playlist to the authenticated user's favorites.
+    ///
+    /// # Arguments
+    ///
+    /// * `playlist_id` - The unique identifier (UUID) of the playlist to favorite
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// client.add_favorite_playlist("12345678-1234-1234-1234-123456789abc").await?;
+    /// println!("Playlist added to favorites!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_favorite_playlist(
+        &self,
+        playlist_id: impl Into<PlaylistId>,
+    ) -> Result<(), Error> {
+        let playlist_id: PlaylistId = playlist_id.into();
+        let user_id = self
+            .get_user_id()
+            .ok_or(Error::UserAuthenticationRequired)?;
+        let url = format!("{TIDAL_API_BASE_URL}/users/{user_id}/favorites/playlists");
+
+        let params = serde_json::json!({
+            "uuids": playlist_id,
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let _: Value = self
+            .do_request(Method::POST, &url, Some(params), None)
+            .await?;
+
+        self.update_cached_favorite(ResourceId::Playlist(playlist_id), true);
+
+        Ok(())
+    }
+
+    /// Remove a playlist from the authenticated user's favorites.
+    ///
+    /// # Arguments
+    ///
+    /// * `playlist_id` - The unique identifier (UUID) of the playlist to remove from favorites
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// client.remove_favorite_playlist("12345678-1234-1234-1234-123456789abc").await?;
+    /// println!("Playlist removed from favorites!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_favorite_playlist(
+        &self,
+        playlist_id: impl Into<PlaylistId>,
+    ) -> Result<(), Error> {
+        let playlist_id: PlaylistId = playlist_id.into();
+        let user_id = self
+            .get_user_id()
+            .ok_or(Error::UserAuthenticationRequired)?;
+        let url = format!("{TIDAL_API_BASE_URL}/users/{user_id}/favorites/playlists/{playlist_id}");
+
+        let params = serde_json::json!({
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let _: Value = self
+            .do_request(Method::DELETE, &url, Some(params), None)
+            .await?;
+
+        self.update_cached_favorite(ResourceId::Playlist(playlist_id), false);
+
+        Ok(())
+    }
+
+    /// Get the playlists the authenticated user created together with those they
+    /// favorited, with pagination and sorting.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Number of playlists to skip (default: 0)
+    /// * `limit` - Maximum number of playlists to return (default: 100)
+    /// * `order` - Sort order (default: Date)
+    /// * `order_direction` - Sort direction (default: Desc)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of playlists, each marked as created or favorited.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::UserPlaylistKind;
+    ///
+    /// let playlists = client.user_and_favorite_playlists(None, Some(20), None, None).await?;
+    /// for entry in playlists.items {
+    ///     let mine = entry.kind == UserPlaylistKind::Created;
+    ///     println!("{} (mine: {mine})", entry.playlist.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_and_favorite_playlists(
+        &self,
+        offset: Option<u32>,
+        limit: Option<u32>,
+        order: Option<Order>,
+        order_direction: Option<OrderDirection>,
+    ) -> Result<List<UserPlaylist>, Error> {
+        let user_id = self
+            .get_user_id()
+            .ok_or(Error::UserAuthenticationRequired)?;
+        let offset = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(100);
+
+        let url = format!("{TIDAL_API_BASE_URL}/users/{user_id}/playlistsAndFavoritePlaylists");
+
+        let params = serde_json::json!({
+            "offset": offset,
+            "limit": limit,
+            "order": order.unwrap_or(Order::Date).as_ref(),
+            "orderDirection": order_direction.unwrap_or(OrderDirection::Desc).as_ref(),
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: List<UserPlaylist> = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+
+    /// Fetch all playlists the authenticated user created or favorited.
+    ///
+    /// A playlist the user both created and favorited is only returned once,
+    /// as created.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Sort order (default: Date)
+    /// * `order_direction` - Sort direction (default: Desc)
+    ///
+    /// # Returns
+    ///
+    /// Returns every playlist in the user's collection, in the requested order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let playlists = client.user_and_favorite_playlists_all(None, None).await?;
+    /// println!("{} playlists", playlists.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_and_favorite_playlists_all(
+        &self,
+        order: Option<Order>,
+        order_direction: Option<OrderDirection>,
+    ) -> Result<Vec<UserPlaylist>, Error> {
+        let playlists = self
+            .fetch_all(
+                DEFAULT_STREAM_PAGE_SIZE,
+                |offset, limit| {
+                    self.user_and_favorite_playlists(
+                        Some(offset),
+                        Some(limit),
+                        order,
+                        order_direction,
+                    )
+                },
+                user_playlist_key,
+            )
+            .await?;
+
+        Ok(dedupe_user_playlists(playlists))
+    }
+
     /// Get recommended tracks for a specific playlist with pagination support.
     ///
     /// This method retrieves tracks that Tidal recommends based on the
@@ -453,6 +1007,8 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let recommendations = client.playlist_recommendations(
     ///     "12345678-1234-1234-1234-123456789abc",
     ///     Some(0),
@@ -465,13 +1021,16 @@ impl TidalClient {
     ///         track.artists[0].name
     ///     );
     /// }
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn playlist_recommendations(
         &self,
-        playlist_id: &str,
+        playlist_id: impl Into<PlaylistId>,
         offset: Option<u32>,
         limit: Option<u32>,
     ) -> Result<List<Track>, Error> {
+        let playlist_id: PlaylistId = playlist_id.into();
         let offset = offset.unwrap_or(0);
         let limit = limit.unwrap_or(5);
         let url = format!("{TIDAL_API_BASE_URL}/playlists/{playlist_id}/recommendations/items");
@@ -496,8 +1055,121 @@ impl TidalClient {
             limit: resp.limit,
             total: resp.total,
             etag: resp.etag,
+            warnings: resp.warnings,
         };
 
         Ok(track_list)
     }
 }
+
+// Identify an entry across a page boundary. The kind is part of the key, so that a
+// favorite entry ending one page doesn't hide a created entry starting the next.
+fn user_playlist_key(entry: &UserPlaylist) -> (UserPlaylistKind, PlaylistId) {
+    (entry.kind, entry.playlist.uuid.clone())
+}
+
+// Keep one entry per playlist, preferring the entry for a playlist the user created
+fn dedupe_user_playlists(playlists: Vec<UserPlaylist>) -> Vec<UserPlaylist> {
+    let created: HashSet<PlaylistId> = playlists
+        .iter()
+        .filter(|entry| entry.kind == UserPlaylistKind::Created)
+        .map(|entry| entry.playlist.uuid.clone())
+        .collect();
+
+    let mut seen = HashSet::new();
+    playlists
+        .into_iter()
+        .filter(|entry| {
+            let uuid = &entry.playlist.uuid;
+            let shadowed = entry.kind == UserPlaylistKind::Favorite && created.contains(uuid);
+            !shadowed && seen.insert(uuid.clone())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(kind: UserPlaylistKind, uuid: &str) -> UserPlaylist {
+        serde_json::from_value(serde_json::json!({
+            "type": kind,
+            "playlist": {
+                "uuid": uuid,
+                "title": "Playlist",
+                "creator": {},
+                "numberOfTracks": 0,
+                "numberOfVideos": 0,
+                "duration": 0,
+                "popularity": 0,
+                "lastUpdated": "2025-02-06T00:00:00.000+0000",
+                "created": "2025-02-04T00:00:00.000+0000",
+                "type": "USER",
+                "publicPlaylist": false,
+            },
+        }))
+        .unwrap()
+    }
+
+    fn page(entries: Vec<UserPlaylist>, offset: usize, total: usize) -> List<UserPlaylist> {
+        List {
+            limit: entries.len(),
+            items: entries,
+            offset,
+            total,
+            ..Default::default()
+        }
+    }
+
+    fn kinds(playlists: &[UserPlaylist]) -> Vec<(UserPlaylistKind, &str)> {
+        playlists
+            .iter()
+            .map(|entry| (entry.kind, entry.playlist.uuid.as_ref()))
+            .collect()
+    }
+
+    #[test]
+    fn test_dedupe_prefers_created_in_either_order() {
+        use UserPlaylistKind::{Created, Favorite};
+
+        let playlists = vec![
+            entry(Favorite, "x"),
+            entry(Created, "y"),
+            entry(Created, "x"),
+        ];
+        assert_eq!(
+            kinds(&dedupe_user_playlists(playlists)),
+            [(Created, "y"), (Created, "x")]
+        );
+
+        let playlists = vec![
+            entry(Created, "x"),
+            entry(Favorite, "y"),
+            entry(Favorite, "x"),
+        ];
+        assert_eq!(
+            kinds(&dedupe_user_playlists(playlists)),
+            [(Created, "x"), (Favorite, "y")]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_created_entry_after_a_page_boundary_is_kept() {
+        use UserPlaylistKind::{Created, Favorite};
+        let client = TidalClient::new("client_id".to_string());
+
+        // Favorite(x) ends the first page and Created(x) starts the second
+        let fetch = |offset: u32, _limit: u32| async move {
+            Ok(match offset {
+                0 => page(vec![entry(Created, "a"), entry(Favorite, "x")], 0, 4),
+                _ => page(vec![entry(Created, "x"), entry(Created, "b")], 2, 4),
+            })
+        };
+
+        let playlists = client.fetch_all(2, fetch, user_playlist_key).await.unwrap();
+        assert_eq!(
+            kinds(&dedupe_user_playlists(playlists)),
+            [(Created, "a"), (Created, "x"), (Created, "b")]
+        );
+    }
+}
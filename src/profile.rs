@@ -0,0 +1,335 @@
+use crate::CursorList;
+use crate::Error;
+#[cfg(feature = "extra-fields")]
+use crate::ExtraFields;
+use crate::TIDAL_API_V2_BASE_URL;
+use crate::TidalClient;
+use crate::id::{ResourceId, UserId};
+use crate::playlist::Playlist;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The public profile of a Tidal user.
+///
+/// Unlike `User`, which describes the authenticated account, this contains only
+/// the information other users can see.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserProfile {
+    /// Unique user identifier
+    #[serde(alias = "id")]
+    pub user_id: UserId,
+    /// Display name of the user
+    #[serde(default)]
+    pub name: String,
+    /// Profile picture identifier or URL
+    pub picture: Option<String>,
+    /// Colors used to render the profile when no picture is set
+    #[serde(default)]
+    pub color: Vec<String>,
+    /// Number of users following this profile
+    pub number_of_followers: Option<u32>,
+    /// Number of users this profile follows
+    pub number_of_follows: Option<u32>,
+    /// Whether the authenticated user follows this profile (set in follow lists)
+    pub im_following: Option<bool>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl UserProfile {
+    /// Generate a URL for the user's profile picture at the specified dimensions.
+    ///
+    /// Tidal returns either a full URL or an image identifier, both are handled.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - Height of the image in pixels
+    /// * `width` - Width of the image in pixels
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(String)` with the full URL if a picture is available,
+    /// or `None` if no picture is set.
+    pub fn picture_url(&self, height: u16, width: u16) -> Option<String> {
+        self.picture.as_ref().map(|picture| {
+            if picture.starts_with("http") {
+                picture.clone()
+            } else {
+                let picture_path = picture.replace('-', "/");
+                format!("https://resources.tidal.com/images/{picture_path}/{height}x{width}.jpg")
+            }
+        })
+    }
+
+    /// Generate the canonical Tidal share URL for this profile.
+    pub fn share_url(&self) -> String {
+        ResourceId::UserProfile(self.user_id).share_url()
+    }
+}
+
+// An entry in a user's public playlists
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct PublicPlaylistItem {
+    playlist: Playlist,
+}
+
+impl TidalClient {
+    /// Get the public profile of a user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The unique identifier of the user
+    ///
+    /// # Returns
+    ///
+    /// Returns the user's `UserProfile`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let profile = client.user_profile(123456789).await?;
+    /// println!("Profile: {}", profile.name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_profile(&self, user_id: impl Into<UserId>) -> Result<UserProfile, Error> {
+        let user_id: UserId = user_id.into();
+        let url = format!("{TIDAL_API_V2_BASE_URL}/profiles/{user_id}");
+        let params = serde_json::json!({
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: UserProfile = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+
+    /// Get the public playlists of a user with cursor pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The unique identifier of the user
+    /// * `limit` - Maximum number of playlists to return (default: 50)
+    /// * `cursor` - Cursor returned by the previous page, or `None` for the first page
+    ///
+    /// # Returns
+    ///
+    /// Returns a page of the user's public playlists.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let mut cursor = None;
+    /// loop {
+    ///     let page = client.user_public_playlists(123456789, None, cursor.as_deref()).await?;
+    ///     for playlist in page.items {
+    ///         println!("Playlist: {}", playlist.title);
+    ///     }
+    ///     match page.cursor {
+    ///         Some(next) => cursor = Some(next),
+    ///         None => break,
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_public_playlists(
+        &self,
+        user_id: impl Into<UserId>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<CursorList<Playlist>, Error> {
+        let user_id: UserId = user_id.into();
+        let url = format!("{TIDAL_API_V2_BASE_URL}/user-playlists/{user_id}/public");
+
+        let resp: CursorList<PublicPlaylistItem> = self
+            .do_request(
+                Method::GET,
+                &url,
+                Some(self.cursor_params(limit, cursor)),
+                None,
+            )
+            .await?;
+
+        Ok(CursorList {
+            items: resp.items.into_iter().map(|item| item.playlist).collect(),
+            cursor: resp.cursor,
+        })
+    }
+
+    /// Get the users following a profile with cursor pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The unique identifier of the user
+    /// * `limit` - Maximum number of profiles to return (default: 50)
+    /// * `cursor` - Cursor returned by the previous page, or `None` for the first page
+    ///
+    /// # Returns
+    ///
+    /// Returns a page of follower profiles.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let followers = client.user_followers(123456789, None, None).await?;
+    /// for follower in followers.items {
+    ///     println!("Follower: {}", follower.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_followers(
+        &self,
+        user_id: impl Into<UserId>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<CursorList<UserProfile>, Error> {
+        let user_id: UserId = user_id.into();
+        let url = format!("{TIDAL_API_V2_BASE_URL}/profiles/{user_id}/followers");
+
+        let resp: CursorList<UserProfile> = self
+            .do_request(
+                Method::GET,
+                &url,
+                Some(self.cursor_params(limit, cursor)),
+                None,
+            )
+            .await?;
+
+        Ok(resp)
+    }
+
+    /// Get the users a profile follows with cursor pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The unique identifier of the user
+    /// * `limit` - Maximum number of profiles to return (default: 50)
+    /// * `cursor` - Cursor returned by the previous page, or `None` for the first page
+    ///
+    /// # Returns
+    ///
+    /// Returns a page of followed profiles.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let following = client.user_following(123456789, None, None).await?;
+    /// for profile in following.items {
+    ///     println!("Following: {}", profile.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn user_following(
+        &self,
+        user_id: impl Into<UserId>,
+        limit: Option<u32>,
+        cursor: Option<&str>,
+    ) -> Result<CursorList<UserProfile>, Error> {
+        let user_id: UserId = user_id.into();
+        let url = format!("{TIDAL_API_V2_BASE_URL}/profiles/{user_id}/following");
+
+        let resp: CursorList<UserProfile> = self
+            .do_request(
+                Method::GET,
+                &url,
+                Some(self.cursor_params(limit, cursor)),
+                None,
+            )
+            .await?;
+
+        Ok(resp)
+    }
+
+    /// Follow a user as the authenticated user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The unique identifier of the user to follow
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// client.follow_user(123456789).await?;
+    /// println!("User followed!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn follow_user(&self, user_id: impl Into<UserId>) -> Result<(), Error> {
+        self.set_following(Method::PUT, user_id.into()).await
+    }
+
+    /// Unfollow a user as the authenticated user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The unique identifier of the user to unfollow
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// client.unfollow_user(123456789).await?;
+    /// println!("User unfollowed!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unfollow_user(&self, user_id: impl Into<UserId>) -> Result<(), Error> {
+        self.set_following(Method::DELETE, user_id.into()).await
+    }
+
+    // Follow (PUT) or unfollow (DELETE) a user
+    async fn set_following(&self, method: Method, user_id: UserId) -> Result<(), Error> {
+        self.get_user_id()
+            .ok_or(Error::UserAuthenticationRequired)?;
+        let url = format!("{TIDAL_API_V2_BASE_URL}/follow");
+
+        let params = serde_json::json!({
+            "trn": format!("trn:user:{user_id}"),
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let _: Value = self.do_request(method, &url, Some(params), None).await?;
+
+        Ok(())
+    }
+
+    pub(crate) fn cursor_params(&self, limit: Option<u32>, cursor: Option<&str>) -> Value {
+        let mut params = serde_json::json!({
+            "limit": limit.unwrap_or(50),
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        if let Some(cursor) = cursor {
+            params["cursor"] = Value::String(cursor.to_string());
+        }
+
+        params
+    }
+}
@@ -1,13 +1,20 @@
+use crate::DEFAULT_STREAM_PAGE_SIZE;
 use crate::Error;
+#[cfg(feature = "extra-fields")]
+use crate::ExtraFields;
 use crate::List;
 use crate::Playlist;
 use crate::Resource;
 use crate::ResourceType;
 use crate::TIDAL_API_BASE_URL;
 use crate::TidalClient;
+use crate::UserProfile;
 use crate::album::Album;
 use crate::artist::Artist;
+use crate::paginate;
 use crate::track::Track;
+use crate::video::Video;
+use futures::Stream;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -82,6 +89,8 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let search_query = tidalrs::SearchQuery::new("The Beatles");
     /// let results = client.search(search_query).await?;
     ///
@@ -91,6 +100,8 @@ impl TidalClient {
     /// for album in results.albums.items {
     ///     println!("Album: {}", album.title);
     /// }
+    /// # Ok(())
+    /// # }
     /// ```
     #[allow(clippy::too_many_arguments)]
     pub async fn search<'a>(&self, search: SearchQuery<'a>) -> Result<SearchResults, Error> {
@@ -151,6 +162,151 @@ impl TidalClient {
 
         Ok(resp)
     }
+
+    /// Stream all tracks matching a search query.
+    ///
+    /// Pages through the matching tracks on demand, fetching the next page only
+    /// once the previous one has been consumed. The query's `limit` is used as
+    /// the page size, while its `offset` and `search_types` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `search` - The search query parameters
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let search_query = tidalrs::SearchQuery::new("The Beatles");
+    /// let mut tracks = std::pin::pin!(client.search_tracks_stream(search_query));
+    /// while let Some(track) = tracks.try_next().await? {
+    ///     println!("Track: {}", track.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_tracks_stream<'a>(
+        &'a self,
+        search: SearchQuery<'a>,
+    ) -> impl Stream<Item = Result<Track, Error>> + 'a {
+        self.search_stream(search, ResourceType::Track, |results| results.tracks)
+    }
+
+    /// Stream all albums matching a search query.
+    ///
+    /// Pages through the matching albums on demand, fetching the next page only
+    /// once the previous one has been consumed. The query's `limit` is used as
+    /// the page size, while its `offset` and `search_types` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `search` - The search query parameters
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let search_query = tidalrs::SearchQuery::new("The Beatles");
+    /// let mut albums = std::pin::pin!(client.search_albums_stream(search_query));
+    /// while let Some(album) = albums.try_next().await? {
+    ///     println!("Album: {}", album.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_albums_stream<'a>(
+        &'a self,
+        search: SearchQuery<'a>,
+    ) -> impl Stream<Item = Result<Album, Error>> + 'a {
+        self.search_stream(search, ResourceType::Album, |results| results.albums)
+    }
+
+    /// Stream all artists matching a search query.
+    ///
+    /// Pages through the matching artists on demand, fetching the next page only
+    /// once the previous one has been consumed. The query's `limit` is used as
+    /// the page size, while its `offset` and `search_types` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `search` - The search query parameters
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let search_query = tidalrs::SearchQuery::new("The Beatles");
+    /// let mut artists = std::pin::pin!(client.search_artists_stream(search_query));
+    /// while let Some(artist) = artists.try_next().await? {
+    ///     println!("Artist: {}", artist.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_artists_stream<'a>(
+        &'a self,
+        search: SearchQuery<'a>,
+    ) -> impl Stream<Item = Result<Artist, Error>> + 'a {
+        self.search_stream(search, ResourceType::Artist, |results| results.artists)
+    }
+
+    /// Stream all playlists matching a search query.
+    ///
+    /// Pages through the matching playlists on demand, fetching the next page only
+    /// once the previous one has been consumed. The query's `limit` is used as
+    /// the page size, while its `offset` and `search_types` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `search` - The search query parameters
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let search_query = tidalrs::SearchQuery::new("The Beatles");
+    /// let mut playlists = std::pin::pin!(client.search_playlists_stream(search_query));
+    /// while let Some(playlist) = playlists.try_next().await? {
+    ///     println!("Playlist: {}", playlist.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_playlists_stream<'a>(
+        &'a self,
+        search: SearchQuery<'a>,
+    ) -> impl Stream<Item = Result<Playlist, Error>> + 'a {
+        self.search_stream(search, ResourceType::Playlist, |results| results.playlists)
+    }
+
+    // Page through the results of a single type of a search query
+    fn search_stream<'a, T: 'a>(
+        &'a self,
+        search: SearchQuery<'a>,
+        resource_type: ResourceType,
+        select: fn(SearchResults) -> List<T>,
+    ) -> impl Stream<Item = Result<T, Error>> + 'a {
+        let page_size = search.limit.unwrap_or(DEFAULT_STREAM_PAGE_SIZE);
+        paginate(page_size, move |offset, limit| {
+            let mut search = search.clone();
+            search.offset = Some(offset);
+            search.limit = Some(limit);
+            search.search_types = Some(vec![resource_type]);
+            async move { self.search(search).await.map(select) }
+        })
+    }
 }
 
 /// Results from a search operation in the Tidal catalog.
@@ -179,21 +335,27 @@ pub struct SearchResults {
     #[serde(default)]
     pub playlists: List<Playlist>,
 
-    /// Matching user profiles (currently as raw JSON)
+    /// Matching user profiles
     #[serde(skip_serializing_if = "List::is_empty")]
     #[serde(default)]
-    pub user_profiles: List<serde_json::Value>,
+    #[serde(alias = "userProfiles")]
+    pub user_profiles: List<UserProfile>,
 
-    /// Matching videos (currently as raw JSON)
+    /// Matching videos
     #[serde(skip_serializing_if = "List::is_empty")]
     #[serde(default)]
-    pub videos: List<serde_json::Value>,
+    pub videos: List<Video>,
 
     /// Top hits across all content types
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[serde(default)]
     #[serde(rename = "topHits")]
     pub top_hits: Vec<Resource>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl SearchResults {
@@ -1,15 +1,26 @@
 use crate::AudioQuality;
+use crate::DEFAULT_STREAM_PAGE_SIZE;
+use crate::Date;
 use crate::Error;
+#[cfg(feature = "extra-fields")]
+use crate::ExtraFields;
 use crate::List;
 use crate::MediaMetadata;
 use crate::Order;
 use crate::OrderDirection;
 use crate::TIDAL_API_BASE_URL;
 use crate::TidalClient;
+use crate::Timestamp;
 use crate::artist::ArtistSummary;
+use crate::deserialize_null_default;
+use crate::id::{AlbumId, MixId, ResourceId, TrackId};
+use crate::mix::TRACK_MIX;
+use crate::paginate;
+use futures::Stream;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use stream_download::storage::memory::MemoryStorageProvider;
 use stream_download::{Settings, StreamDownload};
 
@@ -21,9 +32,14 @@ use stream_download::{Settings, StreamDownload};
 #[serde(rename_all = "camelCase")]
 pub struct Track {
     /// Unique track identifier
-    pub id: u64,
+    pub id: TrackId,
     /// Track number within the album
     pub track_number: u32,
+    /// Number of the disc (volume) of the album the track is on, starting at 1
+    ///
+    /// Track numbers restart on every disc of a multi-disc album
+    #[serde(default = "default_volume_number")]
+    pub volume_number: u32,
     /// List of artists who contributed to this track
     #[serde(default = "Default::default")]
     pub artists: Vec<ArtistSummary>,
@@ -61,6 +77,34 @@ pub struct Track {
     pub bpm: Option<u32>,
 
     pub upload: Option<bool>,
+
+    /// Mixes associated with the track, keyed by mix type
+    #[serde(default, deserialize_with = "deserialize_null_default")]
+    pub mixes: HashMap<String, MixId>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+// Tracks without a volume number are on the first and only disc
+fn default_volume_number() -> u32 {
+    1
+}
+
+impl Track {
+    /// Get the id of the track's radio mix, if Tidal has one for this track.
+    ///
+    /// Pass it to `mix_items` to get the tracks and videos of the radio.
+    pub fn radio_mix_id(&self) -> Option<&MixId> {
+        self.mixes.get(TRACK_MIX)
+    }
+
+    /// Generate the canonical Tidal share URL for this track.
+    pub fn share_url(&self) -> String {
+        ResourceId::Track(self.id).share_url()
+    }
 }
 
 /// A simplified representation of an album used in track listings.
@@ -71,17 +115,33 @@ pub struct Track {
 #[serde(rename_all = "camelCase")]
 pub struct AlbumSummary {
     /// Unique album identifier
-    pub id: u64,
+    pub id: AlbumId,
     /// Album title
     pub title: String,
     /// Album cover image identifier
     pub cover: Option<String>,
     /// Album release date
-    pub release_date: Option<String>,
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::datetime::option_date")
+    )]
+    pub release_date: Option<Date>,
     /// Dominant color extracted from the cover art
     pub vibrant_color: Option<String>,
     /// Video cover identifier (if available)
     pub video_cover: Option<String>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl AlbumSummary {
+    /// Generate the canonical Tidal share URL for this album.
+    pub fn share_url(&self) -> String {
+        ResourceId::Album(self.id).share_url()
+    }
 }
 
 /// Represents a track that has been added to a user's favorites.
@@ -92,9 +152,15 @@ pub struct AlbumSummary {
 #[serde(rename_all = "camelCase")]
 pub struct FavoriteTrack {
     /// ISO timestamp when the track was added to favorites
-    pub created: String,
+    #[cfg_attr(feature = "chrono", serde(with = "crate::datetime::timestamp"))]
+    pub created: Timestamp,
     /// The track data
     pub item: Track,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// A suggested track from a track recommendations response.
@@ -128,15 +194,20 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let stream = client.track_stream(123456789, tidalrs::AudioQuality::Lossless).await?;
     /// println!("Stream URL: {}", stream.primary_url().unwrap());
+    /// # Ok(())
+    /// # }
     /// ```
     #[allow(clippy::too_many_arguments)]
     pub async fn track_stream(
         &self,
-        track_id: u64,
+        track_id: impl Into<TrackId>,
         audio_quality: AudioQuality,
     ) -> Result<TrackStream, Error> {
+        let track_id: TrackId = track_id.into();
         let url = format!("{TIDAL_API_BASE_URL}/tracks/{track_id}/urlpostpaywall");
 
         let audio_quality = match audio_quality {
@@ -172,10 +243,15 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let track = client.track(123456789).await?;
     /// println!("Track: {} by {}", track.title, track.artists[0].name);
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn track(&self, track_id: u64) -> Result<Track, Error> {
+    pub async fn track(&self, track_id: impl Into<TrackId>) -> Result<Track, Error> {
+        let track_id: TrackId = track_id.into();
         let url = format!("{TIDAL_API_BASE_URL}/tracks/{track_id}");
 
         let params = serde_json::json!({
@@ -191,6 +267,120 @@ impl TidalClient {
         Ok(resp)
     }
 
+    /// Get several tracks by ID.
+    ///
+    /// Tracks are requested in chunks of up to 50 IDs, with the chunks fetched
+    /// concurrently, bounded by the client's `get_max_concurrency()` setting.
+    /// Each ID gets its own result: a track that Tidal doesn't return, or that
+    /// fails to decode, is an error for that ID alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `track_ids` - The unique identifiers of the tracks
+    ///
+    /// # Returns
+    ///
+    /// Returns an `(TrackId, Result<Track, Error>)` pair for every requested ID, in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::TrackId;
+    ///
+    /// let results = client.tracks(&[TrackId(123456789), TrackId(987654321)]).await;
+    /// for (track_id, result) in results {
+    ///     match result {
+    ///         Ok(track) => println!("{}: {}", track_id, track.title),
+    ///         Err(e) => println!("{} failed: {}", track_id, e),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn tracks(&self, track_ids: &[TrackId]) -> Vec<(TrackId, Result<Track, Error>)> {
+        self.fetch_by_ids(
+            "tracks",
+            track_ids,
+            |track_id| self.track(track_id),
+            |track: &Track| track.id,
+            ResourceId::Track,
+        )
+        .await
+    }
+
+    /// Find every track in the catalog with an ISRC.
+    ///
+    /// The same recording is often available as several tracks, such as on the
+    /// original album, a deluxe edition and a compilation, all sharing one ISRC.
+    /// Only tracks available in the client's country code are found; use
+    /// `tracks_by_isrc_in_countries` to look in other countries too. Hyphens and
+    /// spaces in the ISRC are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `isrc` - International Standard Recording Code of the recording
+    ///
+    /// # Returns
+    ///
+    /// Returns every matching track available in the client's country, which is
+    /// empty if there are none there.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let tracks = client.tracks_by_isrc("GBAYE9200070").await?;
+    /// for track in tracks {
+    ///     println!("{} on album {}", track.title, track.album.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn tracks_by_isrc(&self, isrc: &str) -> Result<Vec<Track>, Error> {
+        let country_code = self.get_country_code();
+        self.tracks_by_isrc_in_countries(isrc, &[&country_code])
+            .await
+    }
+
+    /// Find every track with an ISRC in the catalogs of several countries.
+    ///
+    /// Tidal only lists the tracks available in the country being asked about,
+    /// so the lookup runs once per country. A track found in more than one
+    /// country is returned once.
+    ///
+    /// # Arguments
+    ///
+    /// * `isrc` - International Standard Recording Code of the recording
+    /// * `countries` - ISO 3166-1 alpha-2 country codes to look in (e.g., "US", "GB")
+    ///
+    /// # Returns
+    ///
+    /// Returns every matching track, in the order the countries are given.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let tracks = client
+    ///     .tracks_by_isrc_in_countries("GBAYE9200070", &["US", "GB", "DE"])
+    ///     .await?;
+    /// println!("{} versions", tracks.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn tracks_by_isrc_in_countries(
+        &self,
+        isrc: &str,
+        countries: &[&str],
+    ) -> Result<Vec<Track>, Error> {
+        self.catalog_lookup("tracks", "isrc", isrc, countries, |track: &Track| track.id)
+            .await
+    }
+
     /// Get recommended tracks for a specific track with pagination support.
     ///
     /// This method retrieves tracks that Tidal recommends based on the
@@ -209,6 +399,8 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let recommendations = client.track_recommendations(123456789, Some(0), Some(20)).await?;
     /// for track in recommendations.items {
     ///     println!("Suggested: {} by {}", 
@@ -216,13 +408,16 @@ impl TidalClient {
     ///         track.artists[0].name
     ///     );
     /// }
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn track_recommendations(
         &self,
-        track_id: u64,
+        track_id: impl Into<TrackId>,
         offset: Option<u32>,
         limit: Option<u32>,
     ) -> Result<List<Track>, Error> {
+        let track_id: TrackId = track_id.into();
         let offset = offset.unwrap_or(0);
         let limit = limit.unwrap_or(5);
         let url = format!("{TIDAL_API_BASE_URL}/tracks/{track_id}/recommendations");
@@ -244,6 +439,7 @@ impl TidalClient {
             limit: resp.limit,
             total: resp.total,
             etag: resp.etag,
+            warnings: resp.warnings,
         };
 
         Ok(tracks)
@@ -266,14 +462,19 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let playback_info = client.track_playback_info(123456789, tidalrs::AudioQuality::Lossless).await?;
     /// println!("Sample rate: {} Hz", playback_info.sample_rate.unwrap_or(0));
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn track_playback_info(
         &self,
-        track_id: u64,
+        track_id: impl Into<TrackId>,
         audio_quality: AudioQuality,
     ) -> Result<TrackPlaybackInfo, Error> {
+        let track_id: TrackId = track_id.into();
         let url = format!("{TIDAL_API_BASE_URL}/tracks/{track_id}/playbackinfo");
 
         let params = serde_json::json!({
@@ -306,16 +507,21 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let dash_info = client.track_dash_playback_info(123456789, tidalrs::AudioQuality::Lossless).await?;
     /// let manifest = dash_info.unpack_manifest()?;
     /// println!("DASH manifest: {}", manifest);
+    /// # Ok(())
+    /// # }
     /// ```
     #[allow(clippy::too_many_arguments)]
     pub async fn track_dash_playback_info(
         &self,
-        track_id: u64,
+        track_id: impl Into<TrackId>,
         audio_quality: AudioQuality,
     ) -> Result<TrackDashPlaybackInfo, Error> {
+        let track_id: TrackId = track_id.into();
         let url = format!("{TIDAL_API_BASE_URL}/tracks/{track_id}/playbackinfopostpaywall");
 
         let audio_quality = match audio_quality {
@@ -355,10 +561,14 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let favorites = client.favorite_tracks(None, Some(10), None, None).await?;
     /// for favorite in favorites.items {
     ///     println!("Favorite: {}", favorite.item.title);
     /// }
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn favorite_tracks(
         &self,
@@ -392,6 +602,77 @@ impl TidalClient {
         Ok(resp)
     }
 
+    /// Stream all of the authenticated user's favorite tracks.
+    ///
+    /// Pages through all of the user's favorite tracks on demand, fetching
+    /// the next page only once the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Sort order (default: Date)
+    /// * `order_direction` - Sort direction (default: Desc)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut favorites = std::pin::pin!(client.favorite_tracks_stream(None, None));
+    /// while let Some(favorite) = favorites.try_next().await? {
+    ///     println!("Favorite: {}", favorite.item.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn favorite_tracks_stream(
+        &self,
+        order: Option<Order>,
+        order_direction: Option<OrderDirection>,
+    ) -> impl Stream<Item = Result<FavoriteTrack, Error>> + '_ {
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.favorite_tracks(Some(offset), Some(limit), order, order_direction)
+        })
+    }
+
+    /// Fetch all of the authenticated user's favorite tracks.
+    ///
+    /// The first page is fetched to learn the total, after which the remaining
+    /// pages are fetched concurrently, bounded by the client's `max_concurrency`.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Sort order (default: Date)
+    /// * `order_direction` - Sort direction (default: Desc)
+    ///
+    /// # Returns
+    ///
+    /// Returns every track the user has favorited, in the requested order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let favorites = client.favorite_tracks_all(None, None).await?;
+    /// println!("{} favorite tracks", favorites.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn favorite_tracks_all(
+        &self,
+        order: Option<Order>,
+        order_direction: Option<OrderDirection>,
+    ) -> Result<Vec<FavoriteTrack>, Error> {
+        self.fetch_all(
+            DEFAULT_STREAM_PAGE_SIZE,
+            |offset, limit| self.favorite_tracks(Some(offset), Some(limit), order, order_direction),
+            |favorite| favorite.item.id,
+        )
+        .await
+    }
+
     /// Add a track to the authenticated user's favorites.
     ///
     /// # Arguments
@@ -401,10 +682,15 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// client.add_favorite_track(123456789).await?;
     /// println!("Track added to favorites!");
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn add_favorite_track(&self, track_id: u64) -> Result<(), Error> {
+    pub async fn add_favorite_track(&self, track_id: impl Into<TrackId>) -> Result<(), Error> {
+        let track_id: TrackId = track_id.into();
         let user_id = self
             .get_user_id()
             .ok_or(Error::UserAuthenticationRequired)?;
@@ -421,6 +707,8 @@ impl TidalClient {
             .do_request(Method::POST, &url, Some(params), None)
             .await?;
 
+        self.update_cached_favorite(ResourceId::Track(track_id), true);
+
         Ok(())
     }
 
@@ -433,10 +721,15 @@ impl TidalClient {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// client.remove_favorite_track(123456789).await?;
     /// println!("Track removed from favorites!");
+    /// # Ok(())
+    /// # }
     /// ```
-    pub async fn remove_favorite_track(&self, track_id: u64) -> Result<(), Error> {
+    pub async fn remove_favorite_track(&self, track_id: impl Into<TrackId>) -> Result<(), Error> {
+        let track_id: TrackId = track_id.into();
         let user_id = self
             .get_user_id()
             .ok_or(Error::UserAuthenticationRequired)?;
@@ -452,8 +745,96 @@ impl TidalClient {
             .do_request(Method::DELETE, &url, Some(params), None)
             .await?;
 
+        self.update_cached_favorite(ResourceId::Track(track_id), false);
+
         Ok(())
     }
+
+    /// Add several tracks to the authenticated user's favorites.
+    ///
+    /// The tracks are sent in batches of comma-separated ids, so a large library
+    /// takes a request per batch rather than one per track. When Tidal rejects a
+    /// batch because of its IDs, its tracks are retried one at a time, so a single
+    /// bad ID doesn't fail the rest of its batch. Any other error, such as a rate
+    /// limit or a server error, fails every track of the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `track_ids` - The unique identifiers of the tracks to favorite
+    ///
+    /// # Returns
+    ///
+    /// Returns a `(TrackId, Result<(), Error>)` pair for every requested ID, in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::TrackId;
+    ///
+    /// let results = client.add_favorite_tracks(&[TrackId(123456789), TrackId(987654321)]).await;
+    /// for (track_id, result) in results {
+    ///     if let Err(e) = result {
+    ///         println!("{} failed: {}", track_id, e);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_favorite_tracks(
+        &self,
+        track_ids: &[TrackId],
+    ) -> Vec<(TrackId, Result<(), Error>)> {
+        self.update_favorites(
+            Method::POST,
+            "tracks",
+            "trackIds",
+            track_ids,
+            ResourceId::Track,
+        )
+        .await
+    }
+
+    /// Remove several tracks from the authenticated user's favorites.
+    ///
+    /// Like `add_favorite_tracks`, the tracks are sent in batches and a batch
+    /// rejected because of its IDs is retried one track at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `track_ids` - The unique identifiers of the tracks to remove from favorites
+    ///
+    /// # Returns
+    ///
+    /// Returns a `(TrackId, Result<(), Error>)` pair for every requested ID, in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::TrackId;
+    ///
+    /// let results = client.remove_favorite_tracks(&[TrackId(123456789), TrackId(987654321)]).await;
+    /// let removed = results.iter().filter(|(_, result)| result.is_ok()).count();
+    /// println!("Removed {removed} tracks");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_favorite_tracks(
+        &self,
+        track_ids: &[TrackId],
+    ) -> Vec<(TrackId, Result<(), Error>)> {
+        self.update_favorites(
+            Method::DELETE,
+            "tracks",
+            "trackIds",
+            track_ids,
+            ResourceId::Track,
+        )
+        .await
+    }
 }
 
 /// Streaming information for a track.
@@ -478,9 +859,14 @@ pub struct TrackStream {
     /// Session ID for the streaming session
     pub streaming_session_id: Option<String>,
     /// Track identifier
-    pub track_id: u64,
+    pub track_id: TrackId,
     /// List of streaming URLs (primary URL is typically first)
     pub urls: Vec<String>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// Playback information for a track.
@@ -513,11 +899,16 @@ pub struct TrackPlaybackInfo {
     /// Sample rate in Hz (if available)
     pub sample_rate: Option<u32>,
     /// Track identifier
-    pub track_id: u64,
+    pub track_id: TrackId,
     /// Peak amplitude for this specific track
     pub track_peak_amplitude: f64,
     /// Replay gain adjustment for this track
     pub track_replay_gain: f64,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 /// DASH-specific playback information for a track.
@@ -550,11 +941,16 @@ pub struct TrackDashPlaybackInfo {
     /// Sample rate in Hz - may be None if format is lossy
     pub sample_rate: Option<u32>,
     /// Track identifier
-    pub track_id: u64,
+    pub track_id: TrackId,
     /// Peak amplitude for this specific track
     pub track_peak_amplitude: f64,
     /// Replay gain adjustment for this track
     pub track_replay_gain: f64,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
 }
 
 impl TrackPlaybackInfo {
@@ -571,9 +967,13 @@ impl TrackPlaybackInfo {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let dash_info = client.track_dash_playback_info(123456789, tidalrs::AudioQuality::Lossless).await?;
     /// let manifest = dash_info.unpack_manifest()?;
     /// println!("DASH manifest: {}", manifest);
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn unpack_manifest(&self) -> Result<String, base64::DecodeError> {
         use base64::Engine;
@@ -597,9 +997,13 @@ impl TrackDashPlaybackInfo {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let dash_info = client.track_dash_playback_info(123456789, tidalrs::AudioQuality::Lossless).await?;
     /// let manifest = dash_info.unpack_manifest()?;
     /// println!("DASH manifest: {}", manifest);
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn unpack_manifest(&self) -> Result<String, base64::DecodeError> {
         use base64::Engine;
@@ -617,7 +1021,7 @@ impl TrackStream {
     /// Returns the first URL from the URLs list, which is typically
     /// the primary streaming endpoint.
     pub fn primary_url(&self) -> Option<&str> {
-        self.urls.get(0).map(|s| s.as_str())
+        self.urls.first().map(|s| s.as_str())
     }
 
     /// Get a buffered, seekable stream of the track.
@@ -634,6 +1038,8 @@ impl TrackStream {
     /// # Example
     ///
     /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
     /// let track_stream = client.track_stream(123456789, tidalrs::AudioQuality::Lossless).await?;
     /// let stream = track_stream.stream().await?;
     ///
@@ -646,6 +1052,8 @@ impl TrackStream {
     /// })
     /// .await
     /// .unwrap();
+    /// # Ok(())
+    /// # }
     /// ```
     pub async fn stream(&self) -> Result<StreamDownload<MemoryStorageProvider>, Error> {
         let url: reqwest::Url = match self.primary_url() {
@@ -0,0 +1,654 @@
+use crate::DEFAULT_STREAM_PAGE_SIZE;
+use crate::Error;
+#[cfg(feature = "extra-fields")]
+use crate::ExtraFields;
+use crate::List;
+use crate::Order;
+use crate::OrderDirection;
+use crate::TIDAL_API_BASE_URL;
+use crate::TidalClient;
+use crate::Timestamp;
+use crate::artist::ArtistSummary;
+use crate::id::{ArtistId, ResourceId, VideoId};
+use crate::paginate;
+use crate::track::AlbumSummary;
+use futures::Stream;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum_macros::{AsRefStr, EnumString};
+
+/// Represents a video from the Tidal catalog, such as a music video or live performance.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Video {
+    /// Unique video identifier
+    pub id: VideoId,
+    /// Video title
+    pub title: String,
+    /// List of artists featured in this video
+    #[serde(default)]
+    pub artists: Vec<ArtistSummary>,
+    /// Album this video belongs to (if any)
+    pub album: Option<AlbumSummary>,
+
+    /// Duration of the video in seconds
+    pub duration: u32,
+    /// Whether the video contains explicit content
+    #[serde(default)]
+    pub explicit: bool,
+    /// Popularity score for the video
+    #[serde(default)]
+    pub popularity: u32,
+    /// Highest available video quality (e.g., "MP4_1080P")
+    pub quality: Option<String>,
+    /// Kind of video (e.g., "Music Video", "Live")
+    #[serde(rename = "type")]
+    pub video_type: Option<String>,
+
+    /// Position of the video on its album
+    #[serde(default)]
+    pub track_number: u32,
+    /// Volume (disc) of its album the video is on
+    #[serde(default)]
+    pub volume_number: u32,
+
+    /// Thumbnail image identifier
+    pub image_id: Option<String>,
+    /// Dominant color extracted from the thumbnail
+    pub vibrant_color: Option<String>,
+
+    /// ISO timestamp when the video was released
+    #[cfg_attr(
+        feature = "chrono",
+        serde(default, with = "crate::datetime::option_timestamp")
+    )]
+    pub release_date: Option<Timestamp>,
+
+    /// Whether streaming is allowed for this video
+    #[serde(default)]
+    pub allow_streaming: bool,
+    /// Whether the video is ready for streaming
+    #[serde(default)]
+    pub stream_ready: bool,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl Video {
+    /// Generate a URL for the video's thumbnail image at the specified dimensions.
+    ///
+    /// Tidal serves video images in sizes such as 160x107, 480x320, 750x500
+    /// and 1080x720. Like `Album::cover_url`, the arguments are placed in the
+    /// URL in order, so `image_url(480, 320)` gets the 480x320 image.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - Height of the image in pixels
+    /// * `width` - Width of the image in pixels
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(String)` with the full URL if an image is available,
+    /// or `None` if no image is set.
+    pub fn image_url(&self, height: u16, width: u16) -> Option<String> {
+        self.image_id.as_ref().map(|image_id| {
+            let image_path = image_id.replace('-', "/");
+            format!("https://resources.tidal.com/images/{image_path}/{height}x{width}.jpg")
+        })
+    }
+
+    /// Generate a URL for a small thumbnail of the video (160x107).
+    pub fn thumbnail_url(&self) -> Option<String> {
+        self.image_url(160, 107)
+    }
+
+    /// Generate the canonical Tidal share URL for this video.
+    pub fn share_url(&self) -> String {
+        ResourceId::Video(self.id).share_url()
+    }
+}
+
+/// Represents a video that has been added to a user's favorites.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteVideo {
+    /// ISO timestamp when the video was added to favorites
+    #[cfg_attr(feature = "chrono", serde(with = "crate::datetime::timestamp"))]
+    pub created: Timestamp,
+    /// The video data
+    pub item: Video,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// Video quality levels available for playback.
+#[derive(Debug, Serialize, Deserialize, EnumString, AsRefStr, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum VideoQuality {
+    /// Low quality (typically 360p or 480p)
+    Low,
+    /// Medium quality (typically 720p)
+    Medium,
+    /// High quality (typically 1080p)
+    High,
+    /// Only the audio track of the video
+    AudioOnly,
+}
+
+/// Playback information for a video.
+///
+/// Videos are streamed over HLS. The manifest is a base64-encoded JSON
+/// document pointing at an HLS playlist, see `hls_manifest()`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoPlaybackInfo {
+    /// Video identifier
+    pub video_id: VideoId,
+    /// Stream type (e.g., "ON_DEMAND")
+    pub stream_type: String,
+    /// Asset presentation format
+    pub asset_presentation: String,
+    /// Video quality as a string
+    pub video_quality: String,
+    /// Base64-encoded manifest data
+    ///
+    /// Use hls_manifest() to get the decoded manifest.
+    pub manifest: String,
+    /// MIME type of the manifest
+    pub manifest_mime_type: String,
+    /// Hash of the manifest for verification
+    pub manifest_hash: Option<String>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+/// A decoded video manifest, pointing at one or more HLS playlists.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoManifest {
+    /// MIME type of the stream (e.g., "application/vnd.apple.mpegurl")
+    pub mime_type: String,
+    /// URLs of the HLS playlists
+    pub urls: Vec<String>,
+
+    /// Fields returned by Tidal that are not modelled by this struct
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: ExtraFields,
+}
+
+impl VideoPlaybackInfo {
+    /// Decode the base64-encoded manifest into a raw string.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `base64::DecodeError` if the manifest cannot be decoded.
+    pub fn unpack_manifest(&self) -> Result<String, base64::DecodeError> {
+        use base64::Engine;
+        let decoded = base64::engine::general_purpose::STANDARD.decode(self.manifest.as_bytes())?;
+
+        Ok(String::from_utf8_lossy(&decoded).into_owned())
+    }
+
+    /// Decode the manifest into the HLS playlist URLs it points at.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the manifest is not valid base64 or not the
+    /// JSON document Tidal uses for HLS streams.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let playback_info = client.video_playback_info(123456789, tidalrs::VideoQuality::High).await?;
+    /// let manifest = playback_info.hls_manifest()?;
+    /// if let Some(url) = manifest.primary_url() {
+    ///     println!("HLS playlist: {}", url);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn hls_manifest(&self) -> Result<VideoManifest, Error> {
+        let manifest = self.unpack_manifest()?;
+        Ok(serde_json::from_str(&manifest)?)
+    }
+}
+
+impl VideoManifest {
+    /// Get the primary HLS playlist URL.
+    pub fn primary_url(&self) -> Option<&str> {
+        self.urls.first().map(|s| s.as_str())
+    }
+}
+
+impl TidalClient {
+    /// Get detailed information about a specific video.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - The unique identifier of the video
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Video` struct containing all video metadata.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let video = client.video(123456789).await?;
+    /// println!("Video: {} ({}s)", video.title, video.duration);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn video(&self, video_id: impl Into<VideoId>) -> Result<Video, Error> {
+        let video_id: VideoId = video_id.into();
+        let url = format!("{TIDAL_API_BASE_URL}/videos/{video_id}");
+        let params = serde_json::json!({
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: Video = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+
+    /// Get all videos of a specific artist with pagination support.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist
+    /// * `offset` - Number of videos to skip (default: 0)
+    /// * `limit` - Maximum number of videos to return (default: 100)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of videos by the specified artist.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let videos = client.artist_videos(123456789, None, Some(20)).await?;
+    /// for video in videos.items {
+    ///     println!("Video: {}", video.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn artist_videos(
+        &self,
+        artist_id: impl Into<ArtistId>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<List<Video>, Error> {
+        let artist_id: ArtistId = artist_id.into();
+        let offset = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(100);
+
+        let url = format!("{TIDAL_API_BASE_URL}/artists/{artist_id}/videos");
+
+        let params = serde_json::json!({
+            "offset": offset,
+            "limit": limit,
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: List<Video> = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+
+    /// Stream all videos of an artist.
+    ///
+    /// Pages through the artist's videos on demand, fetching the next page only
+    /// once the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `artist_id` - The unique identifier of the artist
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut videos = std::pin::pin!(client.artist_videos_stream(123456789));
+    /// while let Some(video) = videos.try_next().await? {
+    ///     println!("Video: {}", video.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn artist_videos_stream(
+        &self,
+        artist_id: impl Into<ArtistId>,
+    ) -> impl Stream<Item = Result<Video, Error>> + '_ {
+        let artist_id: ArtistId = artist_id.into();
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.artist_videos(artist_id, Some(offset), Some(limit))
+        })
+    }
+
+    /// Get the authenticated user's favorite videos with pagination and sorting.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Number of videos to skip (default: 0)
+    /// * `limit` - Maximum number of videos to return (default: 100)
+    /// * `order` - Sort order (default: Date)
+    /// * `order_direction` - Sort direction (default: Desc)
+    ///
+    /// # Returns
+    ///
+    /// Returns a paginated list of videos the user has favorited.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let favorites = client.favorite_videos(None, Some(10), None, None).await?;
+    /// for favorite in favorites.items {
+    ///     println!("Favorite: {}", favorite.item.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn favorite_videos(
+        &self,
+        offset: Option<u32>,
+        limit: Option<u32>,
+        order: Option<Order>,
+        order_direction: Option<OrderDirection>,
+    ) -> Result<List<FavoriteVideo>, Error> {
+        let user_id = self
+            .get_user_id()
+            .ok_or(Error::UserAuthenticationRequired)?;
+        let offset = offset.unwrap_or(0);
+        let limit = limit.unwrap_or(100);
+
+        let url = format!("{TIDAL_API_BASE_URL}/users/{user_id}/favorites/videos");
+
+        let params = serde_json::json!({
+            "offset": offset,
+            "limit": limit,
+            "order": order.unwrap_or(Order::Date).as_ref(),
+            "orderDirection": order_direction.unwrap_or(OrderDirection::Desc).as_ref(),
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let resp: List<FavoriteVideo> = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+
+    /// Stream all of the authenticated user's favorite videos.
+    ///
+    /// Pages through all of the user's favorite videos on demand, fetching
+    /// the next page only once the previous one has been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Sort order (default: Date)
+    /// * `order_direction` - Sort direction (default: Desc)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut favorites = std::pin::pin!(client.favorite_videos_stream(None, None));
+    /// while let Some(favorite) = favorites.try_next().await? {
+    ///     println!("Favorite: {}", favorite.item.title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn favorite_videos_stream(
+        &self,
+        order: Option<Order>,
+        order_direction: Option<OrderDirection>,
+    ) -> impl Stream<Item = Result<FavoriteVideo, Error>> + '_ {
+        paginate(DEFAULT_STREAM_PAGE_SIZE, move |offset, limit| {
+            self.favorite_videos(Some(offset), Some(limit), order, order_direction)
+        })
+    }
+
+    /// Add a video to the authenticated user's favorites.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - The unique identifier of the video to favorite
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// client.add_favorite_video(123456789).await?;
+    /// println!("Video added to favorites!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_favorite_video(&self, video_id: impl Into<VideoId>) -> Result<(), Error> {
+        let video_id: VideoId = video_id.into();
+        let user_id = self
+            .get_user_id()
+            .ok_or(Error::UserAuthenticationRequired)?;
+        let url = format!("{TIDAL_API_BASE_URL}/users/{user_id}/favorites/videos");
+
+        let params = serde_json::json!({
+            "videoIds": video_id,
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let _: Value = self
+            .do_request(Method::POST, &url, Some(params), None)
+            .await?;
+
+        self.update_cached_favorite(ResourceId::Video(video_id), true);
+
+        Ok(())
+    }
+
+    /// Remove a video from the authenticated user's favorites.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - The unique identifier of the video to remove from favorites
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// client.remove_favorite_video(123456789).await?;
+    /// println!("Video removed from favorites!");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_favorite_video(&self, video_id: impl Into<VideoId>) -> Result<(), Error> {
+        let video_id: VideoId = video_id.into();
+        let user_id = self
+            .get_user_id()
+            .ok_or(Error::UserAuthenticationRequired)?;
+        let url = format!("{TIDAL_API_BASE_URL}/users/{user_id}/favorites/videos/{video_id}");
+
+        let params = serde_json::json!({
+            "countryCode": self.get_country_code(),
+            "locale": self.get_locale(),
+            "deviceType": self.get_device_type().as_ref(),
+        });
+
+        let _: Value = self
+            .do_request(Method::DELETE, &url, Some(params), None)
+            .await?;
+
+        self.update_cached_favorite(ResourceId::Video(video_id), false);
+
+        Ok(())
+    }
+
+    /// Add several videos to the authenticated user's favorites.
+    ///
+    /// The videos are sent in batches of comma-separated ids, so a large library
+    /// takes a request per batch rather than one per video. When Tidal rejects a
+    /// batch because of its IDs, its videos are retried one at a time, so a single
+    /// bad ID doesn't fail the rest of its batch. Any other error, such as a rate
+    /// limit or a server error, fails every video of the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_ids` - The unique identifiers of the videos to favorite
+    ///
+    /// # Returns
+    ///
+    /// Returns a `(VideoId, Result<(), Error>)` pair for every requested ID, in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::VideoId;
+    ///
+    /// let results = client.add_favorite_videos(&[VideoId(123456789), VideoId(987654321)]).await;
+    /// for (video_id, result) in results {
+    ///     if let Err(e) = result {
+    ///         println!("{} failed: {}", video_id, e);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_favorite_videos(
+        &self,
+        video_ids: &[VideoId],
+    ) -> Vec<(VideoId, Result<(), Error>)> {
+        self.update_favorites(
+            Method::POST,
+            "videos",
+            "videoIds",
+            video_ids,
+            ResourceId::Video,
+        )
+        .await
+    }
+
+    /// Remove several videos from the authenticated user's favorites.
+    ///
+    /// Like `add_favorite_videos`, the videos are sent in batches and a batch
+    /// rejected because of its IDs is retried one video at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_ids` - The unique identifiers of the videos to remove from favorites
+    ///
+    /// # Returns
+    ///
+    /// Returns a `(VideoId, Result<(), Error>)` pair for every requested ID, in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// use tidalrs::VideoId;
+    ///
+    /// let results = client.remove_favorite_videos(&[VideoId(123456789), VideoId(987654321)]).await;
+    /// let removed = results.iter().filter(|(_, result)| result.is_ok()).count();
+    /// println!("Removed {removed} videos");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_favorite_videos(
+        &self,
+        video_ids: &[VideoId],
+    ) -> Vec<(VideoId, Result<(), Error>)> {
+        self.update_favorites(
+            Method::DELETE,
+            "videos",
+            "videoIds",
+            video_ids,
+            ResourceId::Video,
+        )
+        .await
+    }
+
+    /// Get playback information for a video at the specified quality.
+    ///
+    /// # Arguments
+    ///
+    /// * `video_id` - The unique identifier of the video
+    /// * `video_quality` - The desired video quality level
+    ///
+    /// # Returns
+    ///
+    /// Returns a `VideoPlaybackInfo` whose manifest points at an HLS stream.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let client = tidalrs::TidalClient::new("client_id".to_string());
+    /// let playback_info = client.video_playback_info(123456789, tidalrs::VideoQuality::High).await?;
+    /// println!("Quality: {}", playback_info.video_quality);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn video_playback_info(
+        &self,
+        video_id: impl Into<VideoId>,
+        video_quality: VideoQuality,
+    ) -> Result<VideoPlaybackInfo, Error> {
+        let video_id: VideoId = video_id.into();
+        let url = format!("{TIDAL_API_BASE_URL}/videos/{video_id}/playbackinfopostpaywall");
+
+        let params = serde_json::json!({
+            "videoquality": video_quality.as_ref(),
+            "playbackmode": "STREAM",
+            "assetpresentation": "FULL",
+            "countryCode": self.get_country_code(),
+        });
+
+        let resp: VideoPlaybackInfo = self
+            .do_request(Method::GET, &url, Some(params), None)
+            .await?;
+
+        Ok(resp)
+    }
+}
@@ -0,0 +1,70 @@
+//! Tests for album tracklists.
+//!
+//! This module tests grouping album tracks by disc in `AlbumTracklist::new`,
+//! using tracks from the search result fixture.
+
+use tidalrs::{AlbumTracklist, SearchResults, Track};
+
+fn example_tracks() -> Vec<Track> {
+    let json_str = std::fs::read_to_string("tests/example_search_result.json")
+        .expect("Failed to read example_search_result.json");
+    let search_results: SearchResults = serde_json::from_str(&json_str).unwrap();
+    search_results.tracks.items
+}
+
+fn track(tracks: &[Track], index: usize, volume_number: u32, track_number: u32) -> Track {
+    let mut track = tracks[index].clone();
+    track.volume_number = volume_number;
+    track.track_number = track_number;
+    track
+}
+
+#[test]
+fn test_volume_number_is_deserialized() {
+    let tracks = example_tracks();
+    assert!(tracks.iter().all(|track| track.volume_number == 1));
+
+    let mut json = serde_json::to_value(&tracks[0]).unwrap();
+    json.as_object_mut().unwrap().remove("volumeNumber");
+    let track: Track = serde_json::from_value(json).unwrap();
+    assert_eq!(track.volume_number, 1);
+}
+
+#[test]
+fn test_tracklist_groups_tracks_by_disc() {
+    let tracks = example_tracks();
+    let tracklist = AlbumTracklist::new(vec![
+        track(&tracks, 0, 2, 2),
+        track(&tracks, 1, 1, 2),
+        track(&tracks, 2, 2, 1),
+        track(&tracks, 3, 1, 1),
+    ]);
+
+    assert!(tracklist.is_multi_disc());
+    assert_eq!(tracklist.discs.len(), 2);
+
+    let disc_one = &tracklist.discs[0];
+    assert_eq!(disc_one.number, 1);
+    assert_eq!(disc_one.tracks[0].id, tracks[3].id);
+    assert_eq!(disc_one.tracks[1].id, tracks[1].id);
+    assert_eq!(disc_one.duration, tracks[1].duration + tracks[3].duration);
+
+    let disc_two = &tracklist.discs[1];
+    assert_eq!(disc_two.number, 2);
+    assert_eq!(disc_two.tracks[0].id, tracks[2].id);
+    assert_eq!(disc_two.duration, tracks[0].duration + tracks[2].duration);
+
+    assert_eq!(tracklist.tracks().count(), 4);
+    assert_eq!(
+        tracklist.duration(),
+        tracks[..4].iter().map(|track| track.duration).sum::<u32>()
+    );
+}
+
+#[test]
+fn test_empty_tracklist() {
+    let tracklist = AlbumTracklist::new(vec![]);
+    assert!(tracklist.discs.is_empty());
+    assert!(!tracklist.is_multi_disc());
+    assert_eq!(tracklist.duration(), 0);
+}
@@ -0,0 +1,115 @@
+//! Tests for building artist discographies.
+//!
+//! This module tests grouping, sorting and re-issue removal in
+//! `Discography::new`, using albums from the search result fixture.
+
+use tidalrs::{Album, AlbumId, AlbumType, ArtistId, Discography, SearchResults};
+
+fn example_albums() -> Vec<Album> {
+    let json_str = std::fs::read_to_string("tests/example_search_result.json")
+        .expect("Failed to read example_search_result.json");
+    let search_results: SearchResults = serde_json::from_str(&json_str).unwrap();
+    search_results.albums.items
+}
+
+fn ids(albums: &[Album]) -> Vec<AlbumId> {
+    albums.iter().map(|album| album.id).collect()
+}
+
+#[test]
+fn test_discography_is_sorted_by_release_date() {
+    let albums = example_albums();
+    let discography = Discography::new(ArtistId(14686), albums[..4].to_vec(), vec![], vec![]);
+
+    // 2003-11-25, 2014-04-15, 2018-05-25, 2020-03-13
+    assert_eq!(
+        ids(&discography.albums),
+        vec![
+            AlbumId(3392402),
+            AlbumId(27832552),
+            AlbumId(89179624),
+            AlbumId(134189401)
+        ]
+    );
+    assert_eq!(discography.len(), 4);
+}
+
+#[test]
+fn test_discography_removes_reissues() {
+    let albums = example_albums();
+    let original = albums[1].clone();
+
+    // Same UPC under a different title
+    let mut same_upc = albums[0].clone();
+    same_upc.id = AlbumId(1);
+    same_upc.upc = original.upc.clone();
+
+    // Same title and version with a new UPC and a later release
+    let mut remaster = albums[2].clone();
+    remaster.id = AlbumId(2);
+    remaster.upc = Some("000000000002".to_string());
+    remaster.title = original.title.to_uppercase();
+    remaster.version = original.version.clone();
+    remaster.album_type = original.album_type;
+    remaster.number_of_tracks = original.number_of_tracks;
+
+    // A different version is a separate release
+    let mut deluxe = original.clone();
+    deluxe.id = AlbumId(3);
+    deluxe.upc = Some("000000000003".to_string());
+    deluxe.version = Some("Deluxe Edition".to_string());
+
+    let discography = Discography::new(
+        ArtistId(14686),
+        vec![remaster, original],
+        vec![same_upc],
+        vec![deluxe],
+    );
+
+    assert_eq!(ids(&discography.albums), vec![AlbumId(3392402)]);
+    assert!(discography.eps_and_singles.is_empty());
+    assert_eq!(ids(&discography.compilations), vec![AlbumId(3)]);
+}
+
+#[test]
+fn test_discography_keeps_title_track_singles() {
+    let albums = example_albums();
+    let album = albums[1].clone();
+
+    // A single sharing the album's title
+    let mut single = album.clone();
+    single.id = AlbumId(1);
+    single.upc = Some("000000000001".to_string());
+    single.album_type = AlbumType::Single;
+    single.number_of_tracks = 1;
+
+    // The same title with another track count in the same group is a separate release
+    let mut expanded = album.clone();
+    expanded.id = AlbumId(2);
+    expanded.upc = Some("000000000002".to_string());
+    expanded.number_of_tracks = album.number_of_tracks + 5;
+
+    let discography = Discography::new(
+        ArtistId(14686),
+        vec![album.clone(), expanded],
+        vec![single],
+        vec![],
+    );
+
+    assert_eq!(discography.albums.len(), 2);
+    assert_eq!(ids(&discography.eps_and_singles), vec![AlbumId(1)]);
+}
+
+#[test]
+fn test_discography_splits_compilations_from_appearances() {
+    let albums = example_albums();
+
+    let discography = Discography::new(ArtistId(14686), vec![], vec![], albums[..3].to_vec());
+
+    assert_eq!(ids(&discography.compilations), vec![AlbumId(3392402)]);
+    assert_eq!(
+        ids(&discography.appears_on),
+        vec![AlbumId(89179624), AlbumId(134189401)]
+    );
+    assert_eq!(discography.iter().count(), 3);
+}
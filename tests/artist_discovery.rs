@@ -0,0 +1,51 @@
+//! Tests for the artist discovery models.
+//!
+//! This module tests deserialization of artist links and of the similar
+//! artists list, whose items omit some of the fields of a full artist.
+
+use tidalrs::{Artist, ArtistId, ArtistLink, List};
+
+#[test]
+fn test_deserialize_artist_links() {
+    let links: List<ArtistLink> = serde_json::from_value(serde_json::json!({
+        "limit": 10,
+        "offset": 0,
+        "totalNumberOfItems": 2,
+        "source": "TiVo",
+        "items": [
+            {"url": "http://www.radiohead.com", "siteName": "OFFICIAL_HOMEPAGE"},
+            {"url": "https://twitter.com/radiohead", "siteName": "TWITTER"}
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(links.total, 2);
+    assert_eq!(links.items[0].site_name, "OFFICIAL_HOMEPAGE");
+    assert_eq!(links.items[1].url, "https://twitter.com/radiohead");
+}
+
+#[test]
+fn test_deserialize_similar_artists() {
+    let similar: List<Artist> = serde_json::from_value(serde_json::json!({
+        "limit": 10,
+        "offset": 0,
+        "totalNumberOfItems": 1,
+        "items": [{
+            "id": 3634,
+            "name": "Portishead",
+            "artistTypes": ["ARTIST", "CONTRIBUTOR"],
+            "url": "http://www.tidal.com/artist/3634",
+            "picture": "0d6ed6a4-8d63-4b8b-b2a6-b1f0b4c56a3e",
+            "popularity": 61,
+            "artistRoles": [{"categoryId": -1, "category": "Artist"}],
+            "mixes": {"ARTIST_MIX": "000b9d2e5a5f0b7c1d2e3f4a5b6c7d"},
+            "relationType": "SIMILAR_ARTIST"
+        }]
+    }))
+    .unwrap();
+
+    let artist = &similar.items[0];
+    assert_eq!(artist.id, ArtistId(3634));
+    assert!(!artist.spotlighted);
+    assert!(artist.radio_mix_id().is_some());
+}
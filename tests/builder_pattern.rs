@@ -4,7 +4,7 @@
 //! and that the client can be configured using the fluent builder pattern.
 
 use std::sync::Arc;
-use tidalrs::{Authz, DeviceType, TidalApiError, TidalClient};
+use tidalrs::{Authz, DeviceType, TidalApiError, TidalClient, UserId};
 
 #[test]
 fn test_builder_pattern_basic() {
@@ -57,7 +57,7 @@ fn test_builder_pattern_with_authz() {
 
     let client = TidalClient::new("test_client_id".to_string()).with_authz(authz.clone());
 
-    assert_eq!(client.get_user_id(), Some(12345));
+    assert_eq!(client.get_user_id(), Some(UserId(12345)));
     assert_eq!(client.get_country_code(), "CA"); // Should use authz country code when no explicit setting
     assert_eq!(client.get_locale(), "en_US"); // Should still be default
 
@@ -65,7 +65,7 @@ fn test_builder_pattern_with_authz() {
     if let Some(stored_authz) = client.get_authz() {
         assert_eq!(stored_authz.access_token, "test_access_token");
         assert_eq!(stored_authz.refresh_token, "test_refresh_token");
-        assert_eq!(stored_authz.user_id, 12345);
+        assert_eq!(stored_authz.user_id, UserId(12345));
         assert_eq!(stored_authz.country_code, Some("CA".to_string()));
     } else {
         panic!("Authz should be stored in client");
@@ -112,14 +112,14 @@ fn test_builder_pattern_chaining() {
         });
 
     // Test that all configurations are applied correctly
-    assert_eq!(client.get_user_id(), Some(67890));
+    assert_eq!(client.get_user_id(), Some(UserId(67890)));
     assert_eq!(client.get_country_code(), "DE"); // Explicit setting should override authz
     assert_eq!(client.get_locale(), "de_DE");
     assert_eq!(client.get_device_type(), DeviceType::Browser);
 
     // Verify authz is stored
     if let Some(stored_authz) = client.get_authz() {
-        assert_eq!(stored_authz.user_id, 67890);
+        assert_eq!(stored_authz.user_id, UserId(67890));
         assert_eq!(stored_authz.country_code, Some("AU".to_string()));
     } else {
         panic!("Authz should be stored in client");
@@ -189,6 +189,29 @@ fn test_builder_pattern_with_client() {
     assert_eq!(client.get_device_type(), DeviceType::Browser);
 }
 
+#[test]
+fn test_builder_pattern_with_lenient_lists() {
+    let client = TidalClient::new("test_client_id".to_string());
+    assert!(!client.get_lenient_lists()); // Strict by default
+
+    let client = client.with_lenient_lists(true);
+    assert!(client.get_lenient_lists());
+    assert_eq!(client.get_country_code(), "US"); // Should still be default
+}
+
+#[test]
+fn test_builder_pattern_with_max_concurrency() {
+    let client = TidalClient::new("test_client_id".to_string());
+    assert_eq!(client.get_max_concurrency(), 4); // Default
+
+    let client = client.with_max_concurrency(16);
+    assert_eq!(client.get_max_concurrency(), 16);
+
+    // Zero would stall bulk operations, so it is treated as one
+    let client = client.with_max_concurrency(0);
+    assert_eq!(client.get_max_concurrency(), 1);
+}
+
 #[test]
 fn test_tidal_api_error_deserialization_snake_case() {
     // Test deserialization with snake_case field names
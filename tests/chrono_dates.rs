@@ -0,0 +1,80 @@
+//! Tests for the `chrono` feature.
+//!
+//! This module tests that date and time fields are parsed from the mixed
+//! formats Tidal returns, and that invalid values are rejected.
+#![cfg(feature = "chrono")]
+
+use chrono::{Datelike, NaiveDate, TimeZone, Utc};
+use std::fs;
+use tidalrs::{ArtistBio, SearchResults, parse_date, parse_timestamp};
+
+#[test]
+fn test_dates_parsed_from_search_results() {
+    let json_str = fs::read_to_string("tests/example_search_result.json")
+        .expect("Failed to read example_search_result.json");
+    let search_results: SearchResults = serde_json::from_str(&json_str)
+        .expect("Failed to deserialize example_search_result.json as SearchResults");
+
+    let first_album = &search_results.albums.items[0];
+    assert!(first_album.release_date.is_some());
+    assert!(first_album.stream_start_date.is_some());
+
+    for playlist in &search_results.playlists.items {
+        assert!(playlist.created <= playlist.last_updated);
+    }
+
+    // Release dates can be sorted without any parsing on the caller's side
+    let mut release_dates: Vec<NaiveDate> = search_results
+        .albums
+        .items
+        .iter()
+        .filter_map(|album| album.release_date)
+        .collect();
+    release_dates.sort();
+    assert!(release_dates.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn test_parse_timestamp_formats() {
+    let expected = Utc.with_ymd_and_hms(2019, 4, 8, 15, 34, 29).unwrap();
+
+    for value in [
+        "2019-04-08T15:34:29.000+0000",
+        "2019-04-08T15:34:29+0000",
+        "2019-04-08T15:34:29Z",
+        "2019-04-08T17:34:29+02:00",
+        "2019-04-08T15:34:29.000GMT",
+        "2019-04-08T15:34:29",
+        "1554737669",
+        "1554737669000",
+    ] {
+        assert_eq!(parse_timestamp(value), Some(expected), "{value}");
+    }
+
+    assert_eq!(
+        parse_timestamp("2019-04-08"),
+        Utc.with_ymd_and_hms(2019, 4, 8, 0, 0, 0).single()
+    );
+    assert_eq!(parse_timestamp("not a date"), None);
+}
+
+#[test]
+fn test_parse_date_formats() {
+    let expected = NaiveDate::from_ymd_opt(2003, 11, 25);
+
+    assert_eq!(parse_date("2003-11-25"), expected);
+    assert_eq!(parse_date("2003-11-25T00:00:00.000+0000"), expected);
+    assert_eq!(parse_date("2003").map(|date| date.year()), Some(2003));
+    assert_eq!(parse_date("25/11/2003"), None);
+}
+
+#[test]
+fn test_invalid_timestamp_is_rejected() {
+    let json = r#"{
+        "source": "TiVo",
+        "lastUpdated": "yesterday",
+        "text": "Some biography"
+    }"#;
+
+    assert!(serde_json::from_str::<ArtistBio>(json).is_err());
+}
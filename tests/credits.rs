@@ -0,0 +1,116 @@
+//! Tests for the credits models.
+//!
+//! This module tests parsing of contributor roles and deserialization of
+//! track credits, track contributors and album items with credits.
+
+use tidalrs::{
+    AlbumItem, AlbumItemWithCredits, ArtistId, ContributorRole, Credit, List, SearchResults,
+    TrackContributor, contributors_with_role,
+};
+
+#[test]
+fn test_parse_contributor_roles() {
+    let role = |role: &str| -> ContributorRole { serde_json::from_value(role.into()).unwrap() };
+
+    assert_eq!(role("Producer"), ContributorRole::Producer);
+    assert_eq!(role("MIXING ENGINEER"), ContributorRole::MixingEngineer);
+    assert_eq!(role("Mixer"), ContributorRole::MixingEngineer);
+    assert_eq!(role("Background Vocals"), ContributorRole::Vocals);
+    assert_eq!(
+        role("Theremin"),
+        ContributorRole::Other("Theremin".to_string())
+    );
+
+    assert_eq!(ContributorRole::MixingEngineer.as_ref(), "Mixing Engineer");
+    assert_eq!(
+        serde_json::to_value(ContributorRole::Other("Theremin".to_string())).unwrap(),
+        "Theremin"
+    );
+}
+
+#[test]
+fn test_artist_role_category() {
+    let json_str = std::fs::read_to_string("tests/example_search_result.json")
+        .expect("Failed to read example_search_result.json");
+    let search_results: SearchResults = serde_json::from_str(&json_str).unwrap();
+
+    let artist = &search_results.artists.items[0];
+    assert_eq!(artist.artist_roles[0].role(), ContributorRole::MainArtist);
+}
+
+#[test]
+fn test_deserialize_track_credits() {
+    let credits: Vec<Credit> = serde_json::from_value(serde_json::json!([
+        {
+            "type": "Producer",
+            "contributors": [
+                {"name": "Nigel Godrich", "id": 3545},
+                {"name": "Radiohead"}
+            ]
+        },
+        {
+            "type": "Composer",
+            "contributors": [{"name": "Thom Yorke", "id": 3853}]
+        }
+    ]))
+    .unwrap();
+
+    let producers: Vec<_> = contributors_with_role(&credits, &ContributorRole::Producer)
+        .map(|contributor| contributor.name.as_str())
+        .collect();
+    assert_eq!(producers, vec!["Nigel Godrich", "Radiohead"]);
+    assert_eq!(credits[0].contributors[0].id, Some(ArtistId(3545)));
+    assert_eq!(credits[0].contributors[1].id, None);
+}
+
+#[test]
+fn test_deserialize_track_contributors() {
+    let contributors: List<TrackContributor> = serde_json::from_value(serde_json::json!({
+        "limit": 100,
+        "offset": 0,
+        "totalNumberOfItems": 2,
+        "items": [
+            {"name": "Nigel Godrich", "role": "Producer"},
+            {"name": "Jonny Greenwood", "role": "Guitar", "id": 3854}
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(contributors.items[0].role, ContributorRole::Producer);
+    assert_eq!(
+        contributors.items[1].role,
+        ContributorRole::Other("Guitar".to_string())
+    );
+    assert_eq!(contributors.items[1].id, Some(ArtistId(3854)));
+}
+
+#[test]
+fn test_deserialize_album_items_with_credits() {
+    let json_str = std::fs::read_to_string("tests/example_search_result.json")
+        .expect("Failed to read example_search_result.json");
+    let search_results: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+    let track = search_results["tracks"]["items"][0].clone();
+
+    let items: List<AlbumItemWithCredits> = serde_json::from_value(serde_json::json!({
+        "limit": 100,
+        "offset": 0,
+        "totalNumberOfItems": 1,
+        "items": [{
+            "type": "track",
+            "item": track,
+            "credits": [
+                {"type": "Composer", "contributors": [{"name": "Thom Yorke", "id": 3853}]}
+            ]
+        }]
+    }))
+    .unwrap();
+
+    let item = &items.items[0];
+    assert!(matches!(item.item, AlbumItem::Track(_)));
+    assert_eq!(
+        item.contributors(&ContributorRole::Composer)
+            .next()
+            .map(|contributor| contributor.name.as_str()),
+        Some("Thom Yorke")
+    );
+}
@@ -0,0 +1,91 @@
+//! Tests for the `extra-fields` feature.
+//!
+//! This module tests that fields unknown to the models are captured in the
+//! `extra` map and survive a serialize/deserialize round-trip.
+#![cfg(feature = "extra-fields")]
+
+use std::fs;
+use tidalrs::{ArtistBio, GenericModule, MixImage, Page, PageModule, SearchResults};
+
+#[test]
+fn test_extra_fields_captured_from_search_results() {
+    let json_str = fs::read_to_string("tests/example_search_result.json")
+        .expect("Failed to read example_search_result.json");
+
+    let search_results: SearchResults = serde_json::from_str(&json_str)
+        .expect("Failed to deserialize example_search_result.json as SearchResults");
+
+    // Top-level keys that SearchResults doesn't model
+    assert!(search_results.extra.contains_key("genres"));
+
+    // Track fields that Track doesn't model
+    let first_track = &search_results.tracks.items[0];
+    assert!(first_track.extra.contains_key("streamReady"));
+    assert!(first_track.extra.contains_key("replayGain"));
+
+    // Modelled fields must not leak into the extra map
+    assert!(!first_track.extra.contains_key("title"));
+    assert!(!first_track.extra.contains_key("trackNumber"));
+    assert!(!first_track.extra.contains_key("volumeNumber"));
+}
+
+#[test]
+fn test_extra_fields_round_trip() {
+    let json = r#"{
+        "source": "TiVo",
+        "lastUpdated": "2019-04-08T15:34:29.013GMT",
+        "text": "Some biography",
+        "summary": "",
+        "someNewField": {"nested": [1, 2, 3]}
+    }"#;
+
+    let bio: ArtistBio = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        bio.extra.get("someNewField"),
+        Some(&serde_json::json!({"nested": [1, 2, 3]}))
+    );
+
+    #[allow(unused_mut)]
+    let mut original: serde_json::Value = serde_json::from_str(json).unwrap();
+    // Parsed timestamps are written back out in Tidal's usual format
+    #[cfg(feature = "chrono")]
+    {
+        original["lastUpdated"] = "2019-04-08T15:34:29.013+0000".into();
+    }
+    let round_tripped = serde_json::to_value(&bio).unwrap();
+    assert_eq!(original, round_tripped);
+}
+
+#[test]
+fn test_extra_fields_captured_from_pages_and_mixes() {
+    let image: MixImage = serde_json::from_value(serde_json::json!({
+        "width": 320,
+        "height": 320,
+        "url": "https://images.tidal.com/small.jpg",
+        "format": "jpg"
+    }))
+    .unwrap();
+    assert_eq!(image.extra["format"], "jpg");
+
+    let page: Page = serde_json::from_value(serde_json::json!({
+        "title": "Home",
+        "rows": [{
+            "rowId": 7,
+            "modules": [{"type": "SOCIAL", "title": "Follow", "socialLinks": []}]
+        }]
+    }))
+    .unwrap();
+    assert_eq!(page.rows[0].extra["rowId"], 7);
+
+    let PageModule::Other(module) = &page.rows[0].modules[0] else {
+        panic!("Expected a generic module");
+    };
+    assert_eq!(module.raw["socialLinks"], serde_json::json!([]));
+
+    let json = serde_json::to_value(module).unwrap();
+    assert_eq!(json["type"], "SOCIAL");
+    assert_eq!(json["socialLinks"], serde_json::json!([]));
+    let round_trip: GenericModule = serde_json::from_value(json).unwrap();
+    assert_eq!(round_trip.module_type, "SOCIAL");
+    assert_eq!(round_trip.raw, module.raw);
+}
@@ -0,0 +1,93 @@
+//! Tests for favorite ids and batch favorite updates.
+//!
+//! This module tests deserialization of the favorite ids response,
+//! checking, adding and removing favorites by resource id, and the per-id
+//! results of batch favorite updates.
+
+use tidalrs::{
+    AlbumId, ArtistId, Error, FavoriteIds, PlaylistId, ResourceId, TidalClient, TrackId, UserId,
+    VideoId,
+};
+
+fn example_favorite_ids() -> FavoriteIds {
+    serde_json::from_value(serde_json::json!({
+        "TRACK": ["77646168", "77646169"],
+        "ALBUM": ["77646164"],
+        "ARTIST": ["3634"],
+        "PLAYLIST": ["0c1b2a3d-4e5f-6789-0abc-def123456789"],
+        "VIDEO": []
+    }))
+    .unwrap()
+}
+
+#[test]
+fn test_deserialize_favorite_ids() {
+    let ids = example_favorite_ids();
+
+    assert_eq!(ids.len(), 5);
+    assert!(ids.contains(&ResourceId::Track(TrackId(77646168))));
+    assert!(ids.contains(&ResourceId::Album(AlbumId(77646164))));
+    assert!(ids.contains(&ResourceId::Artist(ArtistId(3634))));
+    assert!(ids.contains(&ResourceId::Playlist(PlaylistId::from(
+        "0c1b2a3d-4e5f-6789-0abc-def123456789"
+    ))));
+    assert!(!ids.contains(&ResourceId::Album(AlbumId(77646168))));
+    assert!(!ids.contains(&ResourceId::UserProfile(UserId(3634))));
+}
+
+#[test]
+fn test_missing_and_numeric_ids() {
+    let ids: FavoriteIds = serde_json::from_value(serde_json::json!({
+        "TRACK": [77646168],
+        "VIDEO": null
+    }))
+    .unwrap();
+
+    assert!(ids.contains(&ResourceId::Track(TrackId(77646168))));
+    assert!(ids.videos.is_empty());
+    assert!(ids.albums.is_empty());
+
+    let round_trip: FavoriteIds =
+        serde_json::from_value(serde_json::to_value(&ids).unwrap()).unwrap();
+    assert_eq!(round_trip, ids);
+
+    assert!(serde_json::from_value::<FavoriteIds>(serde_json::json!({"TRACK": ["abc"]})).is_err());
+}
+
+#[test]
+fn test_insert_and_remove() {
+    let mut ids = example_favorite_ids();
+    let video = ResourceId::Video(VideoId(75623239));
+
+    assert!(ids.insert(video.clone()));
+    assert!(!ids.insert(video.clone()));
+    assert!(ids.contains(&video));
+
+    assert!(ids.remove(&video));
+    assert!(!ids.remove(&video));
+    assert!(!ids.contains(&video));
+
+    assert!(!ids.insert(ResourceId::UserProfile(UserId(1))));
+    assert_eq!(ids.len(), 5);
+}
+
+#[tokio::test]
+async fn test_batch_update_reports_every_id() {
+    let client = TidalClient::new("client_id".to_string());
+    let track_ids: Vec<TrackId> = (1..=120).map(TrackId).collect();
+
+    let results = client.add_favorite_tracks(&track_ids).await;
+    assert_eq!(results.len(), track_ids.len());
+    for ((track_id, result), expected) in results.iter().zip(&track_ids) {
+        assert_eq!(track_id, expected);
+        assert!(matches!(result, Err(Error::UserAuthenticationRequired)));
+    }
+
+    let results = client
+        .remove_favorite_albums(&[AlbumId(1), AlbumId(2)])
+        .await;
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|(_, result)| result.is_err()));
+
+    assert!(client.add_favorite_artists(&[]).await.is_empty());
+}
@@ -0,0 +1,54 @@
+//! Tests for the favorite playlist models.
+//!
+//! This module tests deserialization of favorite playlists and of the
+//! combined view of created and favorited playlists.
+
+use tidalrs::{FavoritePlaylist, List, UserPlaylist, UserPlaylistKind};
+
+fn example_playlist() -> serde_json::Value {
+    let json_str = std::fs::read_to_string("tests/example_search_result.json")
+        .expect("Failed to read example_search_result.json");
+    let search: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+    search["playlists"]["items"][0].clone()
+}
+
+#[test]
+fn test_deserialize_favorite_playlists() {
+    let playlist = example_playlist();
+    let favorites: List<FavoritePlaylist> = serde_json::from_value(serde_json::json!({
+        "limit": 10,
+        "offset": 0,
+        "totalNumberOfItems": 1,
+        "items": [{"created": "2024-03-01T12:00:00.000+0000", "item": playlist}]
+    }))
+    .unwrap();
+
+    assert_eq!(favorites.total, 1);
+    assert_eq!(
+        favorites.items[0].item.uuid.as_str(),
+        playlist["uuid"].as_str().unwrap()
+    );
+}
+
+#[test]
+fn test_deserialize_user_and_favorite_playlists() {
+    let playlist = example_playlist();
+    let playlists: List<UserPlaylist> = serde_json::from_value(serde_json::json!({
+        "limit": 10,
+        "offset": 0,
+        "totalNumberOfItems": 2,
+        "items": [
+            {"type": "USER_CREATED", "created": "2024-03-01T12:00:00.000+0000", "playlist": playlist},
+            {"type": "USER_FAVORITE", "playlist": playlist}
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(playlists.items[0].kind, UserPlaylistKind::Created);
+    assert!(playlists.items[0].created.is_some());
+    assert_eq!(playlists.items[1].kind, UserPlaylistKind::Favorite);
+    assert!(playlists.items[1].created.is_none());
+
+    let json = serde_json::to_value(&playlists.items[1]).unwrap();
+    assert_eq!(json["type"], "USER_FAVORITE");
+}
@@ -0,0 +1,59 @@
+//! Tests for the genre models.
+//!
+//! This module tests deserialization of the genre catalog and building genre
+//! image URLs.
+
+use tidalrs::Genre;
+
+fn example_genres() -> serde_json::Value {
+    serde_json::json!([
+        {
+            "name": "Pop",
+            "path": "Pop",
+            "hasPlaylists": true,
+            "hasArtists": false,
+            "hasAlbums": true,
+            "hasTracks": true,
+            "hasVideos": true,
+            "image": "b8b3e1a4-5a9a-4d3f-a2a5-6a3b1c1d2e3f"
+        },
+        {
+            "name": "Hip-Hop",
+            "path": "Hiphop",
+            "hasPlaylists": true,
+            "hasAlbums": true,
+            "image": null
+        }
+    ])
+}
+
+#[test]
+fn test_deserialize_genres() {
+    let genres: Vec<Genre> = serde_json::from_value(example_genres()).unwrap();
+    assert_eq!(genres.len(), 2);
+
+    assert_eq!(genres[0].name, "Pop");
+    assert!(genres[0].has_tracks);
+    assert!(!genres[0].has_artists);
+    assert_eq!(
+        genres[0].image_url(160, 320).as_deref(),
+        Some("https://resources.tidal.com/images/b8b3e1a4/5a9a/4d3f/a2a5/6a3b1c1d2e3f/160x320.jpg")
+    );
+
+    assert_eq!(genres[1].path, "Hiphop");
+    assert!(!genres[1].has_tracks);
+    assert!(!genres[1].has_videos);
+    assert_eq!(genres[1].image_url(160, 320), None);
+}
+
+#[test]
+fn test_genre_round_trip() {
+    let genres: Vec<Genre> = serde_json::from_value(example_genres()).unwrap();
+    let json = serde_json::to_value(&genres).unwrap();
+
+    assert_eq!(json[1]["hasPlaylists"], true);
+    assert_eq!(json[1]["hasTracks"], false);
+
+    let round_trip: Vec<Genre> = serde_json::from_value(json).unwrap();
+    assert_eq!(round_trip, genres);
+}
@@ -0,0 +1,58 @@
+//! Tests for the strongly typed resource identifiers.
+//!
+//! This module tests parsing, formatting and serde support of the id
+//! newtypes, and that models expose typed ids.
+
+use std::fs;
+use tidalrs::{
+    AlbumId, ArtistId, PlaylistId, Resource, ResourceId, ResourceType, SearchResults, TrackId,
+    UserId,
+};
+
+#[test]
+fn test_numeric_id_parse_and_display() {
+    let track_id: TrackId = "123456789".parse().unwrap();
+    assert_eq!(track_id, TrackId(123456789));
+    assert_eq!(track_id.to_string(), "123456789");
+
+    assert!("not-a-number".parse::<AlbumId>().is_err());
+    assert_eq!(u64::from(ArtistId(42)), 42);
+}
+
+#[test]
+fn test_playlist_id_parse_and_display() {
+    let playlist_id: PlaylistId = "12345678-1234-1234-1234-123456789abc".parse().unwrap();
+    assert_eq!(playlist_id.as_str(), "12345678-1234-1234-1234-123456789abc");
+    assert_eq!(
+        playlist_id.to_string(),
+        "12345678-1234-1234-1234-123456789abc"
+    );
+}
+
+#[test]
+fn test_id_serde_is_transparent() {
+    assert_eq!(serde_json::to_string(&UserId(12345)).unwrap(), "12345");
+    assert_eq!(
+        serde_json::from_str::<TrackId>("987654321").unwrap(),
+        TrackId(987654321)
+    );
+    assert_eq!(
+        serde_json::from_str::<PlaylistId>(r#""abc-def""#).unwrap(),
+        PlaylistId("abc-def".to_string())
+    );
+}
+
+#[test]
+fn test_resource_id_is_typed() {
+    let json_str = fs::read_to_string("tests/example_search_result.json")
+        .expect("Failed to read example_search_result.json");
+    let search_results: SearchResults = serde_json::from_str(&json_str).unwrap();
+
+    let track = search_results.tracks.items[0].clone();
+    let track_id = track.id;
+    let resource = Resource::Tracks(track);
+
+    assert_eq!(resource.id(), ResourceId::Track(track_id));
+    assert!(matches!(resource.id().resource_type(), ResourceType::Track));
+    assert_eq!(resource.id().to_string(), track_id.to_string());
+}
@@ -0,0 +1,64 @@
+//! Tests for lenient decoding of paginated lists.
+//!
+//! This module tests that a malformed item fails a `List<T>` in the default
+//! strict mode, and is skipped and reported as a warning in lenient mode.
+
+use std::fs;
+use tidalrs::{List, Track};
+
+fn tracks_with_one_broken_item() -> serde_json::Value {
+    let json_str = fs::read_to_string("tests/example_search_result.json")
+        .expect("Failed to read example_search_result.json");
+    let search_results: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+
+    let mut tracks = search_results["tracks"].clone();
+    tracks["items"][1]["album"] = serde_json::Value::Null;
+    tracks
+}
+
+#[test]
+fn test_strict_list_fails_on_malformed_item() {
+    let tracks = tracks_with_one_broken_item();
+
+    let result = serde_json::from_value::<List<Track>>(tracks);
+    assert!(
+        result.is_err(),
+        "Strict decoding should fail on a malformed item"
+    );
+}
+
+#[test]
+fn test_lenient_list_skips_malformed_item() {
+    let tracks = tracks_with_one_broken_item();
+    let raw_count = tracks["items"].as_array().unwrap().len();
+    let broken_id = tracks["items"][1]["id"].clone();
+
+    let list = List::<Track>::from_value_lenient(tracks)
+        .expect("Lenient decoding should not fail on a malformed item");
+
+    assert_eq!(list.items.len(), raw_count - 1);
+    assert_eq!(list.warnings.len(), 1);
+
+    let warning = &list.warnings[0];
+    assert_eq!(warning.index, 1);
+    assert_eq!(warning.raw["id"], broken_id);
+    assert!(!warning.message.is_empty());
+
+    // Skipped items still count as consumed when paging
+    assert_eq!(list.num_left(), list.total - list.offset - raw_count);
+}
+
+#[test]
+fn test_lenient_list_without_errors_has_no_warnings() {
+    let json_str = fs::read_to_string("tests/example_search_result.json")
+        .expect("Failed to read example_search_result.json");
+    let search_results: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+
+    let list = List::<Track>::from_value_lenient(search_results["tracks"].clone()).unwrap();
+
+    assert!(list.warnings.is_empty());
+    assert_eq!(
+        list.items.len(),
+        search_results["tracks"]["items"].as_array().unwrap().len()
+    );
+}
@@ -0,0 +1,40 @@
+//! Tests for paging arithmetic of `List<T>`.
+//!
+//! This module tests that `num_left` stays correct when the reported total
+//! changes while a collection is being paged through.
+
+use std::fs;
+use tidalrs::{List, Track};
+
+fn track_list() -> List<Track> {
+    let json_str = fs::read_to_string("tests/example_search_result.json")
+        .expect("Failed to read example_search_result.json");
+    let search_results: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+
+    serde_json::from_value(search_results["tracks"].clone()).unwrap()
+}
+
+#[test]
+fn test_num_left_counts_remaining_items() {
+    let mut list = track_list();
+    let page_size = list.items.len();
+    list.offset = 0;
+    list.total = page_size + 25;
+
+    assert_eq!(list.num_left(), 25);
+}
+
+#[test]
+fn test_num_left_saturates_when_total_shrinks() {
+    let mut list = track_list();
+    let page_size = list.items.len();
+
+    // The collection shrank below the end of this page while paging
+    list.offset = 10;
+    list.total = 10 + page_size - 1;
+    assert_eq!(list.num_left(), 0);
+
+    // The collection shrank below the start of this page
+    list.total = 5;
+    assert_eq!(list.num_left(), 0);
+}
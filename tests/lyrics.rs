@@ -0,0 +1,90 @@
+//! Tests for lyrics.
+//!
+//! This module tests deserialization of track lyrics and reading and
+//! writing of time-synced lyrics in LRC format.
+
+use std::time::Duration;
+use tidalrs::{LyricLine, Lyrics, TrackId, parse_lrc, write_lrc};
+
+fn line(millis: u64, text: &str) -> LyricLine {
+    LyricLine {
+        time: Duration::from_millis(millis),
+        text: text.to_string(),
+    }
+}
+
+#[test]
+fn test_deserialize_lyrics() {
+    let lyrics: Lyrics = serde_json::from_value(serde_json::json!({
+        "trackId": 58990517,
+        "lyricsProvider": "MusixMatch",
+        "providerCommontrackId": "2760434",
+        "providerLyricsId": "31065281",
+        "lyrics": "When you were here before\nCouldn't look you in the eye",
+        "subtitles": "[00:22.91] When you were here before\n[00:27.79] Couldn't look you in the eye\n[00:32.52] ",
+        "isRightToLeft": false
+    }))
+    .unwrap();
+
+    assert_eq!(lyrics.track_id, TrackId(58990517));
+    assert_eq!(lyrics.lyrics_provider, "MusixMatch");
+    assert!(lyrics.copyright.is_none());
+    assert_eq!(
+        lyrics.synced_lines(),
+        vec![
+            line(22_910, "When you were here before"),
+            line(27_790, "Couldn't look you in the eye"),
+            line(32_520, ""),
+        ]
+    );
+}
+
+#[test]
+fn test_lyrics_without_subtitles() {
+    let lyrics: Lyrics = serde_json::from_value(serde_json::json!({
+        "trackId": 1,
+        "lyricsProvider": "MusixMatch",
+        "lyrics": "La la la",
+        "subtitles": null
+    }))
+    .unwrap();
+
+    assert!(lyrics.synced_lines().is_empty());
+}
+
+#[test]
+fn test_parse_lrc() {
+    let lrc = "[ar:Radiohead]\n\
+               [ti:Creep]\n\
+               \n\
+               [00:05]Intro\n\
+               [01:12.340]But I'm a creep\n\
+               [00:45.5][02:10:25]I'm a weirdo\n\
+               not a timed line\n";
+
+    assert_eq!(
+        parse_lrc(lrc),
+        vec![
+            line(5_000, "Intro"),
+            line(45_500, "I'm a weirdo"),
+            line(72_340, "But I'm a creep"),
+            line(130_250, "I'm a weirdo"),
+        ]
+    );
+}
+
+#[test]
+fn test_write_lrc_round_trip() {
+    let lines = vec![
+        line(0, "Start"),
+        line(72_340, "But I'm a creep"),
+        line(6_000_000, "Long track"),
+    ];
+
+    let lrc = write_lrc(&lines);
+    assert_eq!(
+        lrc,
+        "[00:00.00]Start\n[01:12.34]But I'm a creep\n[100:00.00]Long track\n"
+    );
+    assert_eq!(parse_lrc(&lrc), lines);
+}
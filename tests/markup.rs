@@ -0,0 +1,143 @@
+//! Tests for Tidal's inline link markup.
+//!
+//! This module tests parsing of wimpLink tags in album reviews and artist
+//! bios into text and typed link segments, and rendering them as plain text,
+//! Markdown and HTML.
+
+use tidalrs::{
+    AlbumId, AlbumReview, ArtistBio, ArtistId, MarkupSegment, ResourceId, TrackId, parse_markup,
+    render_html, render_markdown, render_plain_text,
+};
+
+fn text(text: &str) -> MarkupSegment {
+    MarkupSegment::Text(text.to_string())
+}
+
+fn link(target: impl Into<ResourceId>, text: &str) -> MarkupSegment {
+    MarkupSegment::Link {
+        target: target.into(),
+        text: text.to_string(),
+    }
+}
+
+#[test]
+fn test_parse_links() {
+    let segments = parse_markup(
+        r#"After [wimpLink albumId="3392402"]This Is Not a Test![/wimpLink], [wimpLink artistId="14686"]Missy Elliott[/wimpLink] released [wimpLink trackId="58990517"]Lose Control[/wimpLink]"#,
+    );
+
+    assert_eq!(
+        segments,
+        vec![
+            text("After "),
+            link(AlbumId(3392402), "This Is Not a Test!"),
+            text(", "),
+            link(ArtistId(14686), "Missy Elliott"),
+            text(" released "),
+            link(TrackId(58990517), "Lose Control"),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_plain_text() {
+    assert_eq!(parse_markup("No links here"), vec![text("No links here")]);
+    assert!(parse_markup("").is_empty());
+}
+
+#[test]
+fn test_unknown_and_broken_links_are_text() {
+    assert_eq!(
+        parse_markup(
+            r#"See [wimpLink labelId="12"]Interscope[/wimpLink] and [wimpLink artistId="x"]someone[/wimpLink]."#
+        ),
+        vec![text("See Interscope and someone.")]
+    );
+
+    assert_eq!(
+        parse_markup(r#"Cut off [wimpLink artistId="14686"]Missy"#),
+        vec![text(r#"Cut off [wimpLink artistId="14686"]Missy"#)]
+    );
+}
+
+#[test]
+fn test_album_review_segments() {
+    let review: AlbumReview = serde_json::from_value(serde_json::json!({
+        "source": "TiVo",
+        "lastUpdated": "2019-04-08T15:34:29.013GMT",
+        "text": r#"The fifth album from [wimpLink artistId="14686"]Missy Elliott[/wimpLink]."#,
+        "summary": ""
+    }))
+    .unwrap();
+
+    assert_eq!(
+        review.text_segments()[1],
+        link(ArtistId(14686), "Missy Elliott")
+    );
+    assert!(review.summary_segments().is_empty());
+}
+
+#[test]
+fn test_line_breaks_become_newlines() {
+    assert_eq!(
+        parse_markup("One<br/>Two<br />Three<br>Four <brackets>"),
+        vec![text("One\nTwo\nThree\nFour <brackets>")]
+    );
+}
+
+#[test]
+fn test_line_breaks_ignore_case_and_spacing() {
+    assert_eq!(
+        parse_markup("One<BR>Two<Br/>Three<bR / >Four<BR  />Five<BRACKET>"),
+        vec![text("One\nTwo\nThree\nFour\nFive<BRACKET>")]
+    );
+}
+
+#[test]
+fn test_line_breaks_in_link_text() {
+    assert_eq!(
+        parse_markup(r#"See [wimpLink albumId="3392402"]This Is<BR/>Not a Test![/wimpLink]<br>"#),
+        vec![
+            text("See "),
+            link(AlbumId(3392402), "This Is\nNot a Test!"),
+            text("\n")
+        ]
+    );
+}
+
+#[test]
+fn test_render_markup() {
+    let segments = parse_markup(
+        r#"Fans of [wimpLink artistId="14686"]Missy_Elliott[/wimpLink] & <Timbaland>:<br/>see [wimpLink albumId="3392402"]This Is Not a Test![/wimpLink]"#,
+    );
+
+    assert_eq!(
+        render_plain_text(&segments),
+        "Fans of Missy_Elliott & <Timbaland>:\nsee This Is Not a Test!"
+    );
+    assert_eq!(
+        render_markdown(&segments),
+        "Fans of [Missy\\_Elliott](https://tidal.com/browse/artist/14686) & \\<Timbaland\\>:  \nsee [This Is Not a Test!](https://tidal.com/browse/album/3392402)"
+    );
+    assert_eq!(
+        render_html(&segments),
+        "Fans of <a href=\"https://tidal.com/browse/artist/14686\" data-tidal-id=\"14686\">Missy_Elliott</a> &amp; &lt;Timbaland&gt;:<br>\nsee <a href=\"https://tidal.com/browse/album/3392402\" data-tidal-id=\"3392402\">This Is Not a Test!</a>"
+    );
+}
+
+#[test]
+fn test_artist_bio_segments() {
+    let bio: ArtistBio = serde_json::from_value(serde_json::json!({
+        "source": "TiVo",
+        "lastUpdated": "2019-04-08T15:34:29.013GMT",
+        "text": r#"Influenced by [wimpLink artistId="3634"]Portishead[/wimpLink].<br/><br/>More."#,
+        "summary": r#"Trip-hop from [wimpLink artistId="3634"]Bristol[/wimpLink]"#
+    }))
+    .unwrap();
+
+    assert_eq!(
+        render_plain_text(&bio.text_segments()),
+        "Influenced by Portishead.\n\nMore."
+    );
+    assert_eq!(bio.summary_segments()[1], link(ArtistId(3634), "Bristol"));
+}
@@ -0,0 +1,92 @@
+//! Tests for the mix models.
+//!
+//! This module tests deserialization of mixes and mix items, and looking up
+//! artist and track radio mixes from the search result fixture.
+
+use tidalrs::{List, Mix, MixId, MixItem, SearchResults};
+
+fn example_mix() -> serde_json::Value {
+    serde_json::json!({
+        "id": "0009a57ac9b5a40d812c7f51b0f92f",
+        "title": "Radiohead Radio",
+        "subTitle": "Radiohead, Portishead, Massive Attack",
+        "mixType": "ARTIST_MIX",
+        "images": {
+            "SMALL": {"width": 320, "height": 320, "url": "https://images.tidal.com/small.jpg"},
+            "MEDIUM": {"width": 640, "height": 640, "url": "https://images.tidal.com/medium.jpg"},
+            "LARGE": {"width": 1500, "height": 1500, "url": "https://images.tidal.com/large.jpg"}
+        }
+    })
+}
+
+fn example_search_results() -> SearchResults {
+    let json_str = std::fs::read_to_string("tests/example_search_result.json")
+        .expect("Failed to read example_search_result.json");
+    serde_json::from_str(&json_str).unwrap()
+}
+
+#[test]
+fn test_deserialize_mix() {
+    let mix: Mix = serde_json::from_value(example_mix()).unwrap();
+
+    assert_eq!(mix.id, MixId::from("0009a57ac9b5a40d812c7f51b0f92f"));
+    assert_eq!(mix.title, "Radiohead Radio");
+    assert_eq!(mix.mix_type.as_deref(), Some("ARTIST_MIX"));
+    assert_eq!(mix.images.len(), 3);
+    assert_eq!(mix.image_url(), Some("https://images.tidal.com/large.jpg"));
+    assert_eq!(
+        mix.share_url(),
+        "https://tidal.com/browse/mix/0009a57ac9b5a40d812c7f51b0f92f"
+    );
+}
+
+#[test]
+fn test_deserialize_mix_without_images() {
+    let mix: Mix = serde_json::from_value(serde_json::json!({
+        "id": "001b1b5e4c2f3d8a9e7f6c5d4b3a21",
+        "title": "My Daily Discovery",
+        "type": "DISCOVERY_MIX"
+    }))
+    .unwrap();
+
+    assert_eq!(mix.mix_type.as_deref(), Some("DISCOVERY_MIX"));
+    assert!(mix.sub_title.is_empty());
+    assert!(mix.image_url().is_none());
+}
+
+#[test]
+fn test_deserialize_mix_items() {
+    let search_results = example_search_results();
+    let track = serde_json::to_value(&search_results.tracks.items[0]).unwrap();
+
+    let items: List<MixItem> = serde_json::from_value(serde_json::json!({
+        "limit": 100,
+        "offset": 0,
+        "totalNumberOfItems": 1,
+        "items": [{"type": "track", "item": track}]
+    }))
+    .unwrap();
+
+    assert!(
+        matches!(&items.items[0], MixItem::Track(t) if t.id == search_results.tracks.items[0].id)
+    );
+}
+
+#[test]
+fn test_radio_mix_ids() {
+    let search_results = example_search_results();
+
+    let artist = &search_results.artists.items[0];
+    assert_eq!(
+        artist.radio_mix_id(),
+        Some(&MixId::from("0009a57ac9b5a40d812c7f51b0f92f"))
+    );
+
+    assert!(
+        search_results
+            .tracks
+            .items
+            .iter()
+            .any(|track| track.radio_mix_id().is_some())
+    );
+}
@@ -0,0 +1,227 @@
+//! Tests for editorial pages.
+//!
+//! This module tests deserialization of pages into typed modules, the
+//! fallback for unknown modules, errors for malformed ones, and serialization
+//! round-trips.
+
+use tidalrs::{AlbumId, AlbumItem, Page, PageModule, PlaylistId, ResourceId};
+
+fn example_album_page() -> Page {
+    let json_str = std::fs::read_to_string("tests/example_album_page.json")
+        .expect("Failed to read example_album_page.json");
+    serde_json::from_str(&json_str).expect("Failed to deserialize example_album_page.json")
+}
+
+#[test]
+fn test_deserialize_album_page() {
+    let page = example_album_page();
+    let modules: Vec<_> = page.modules().collect();
+    assert_eq!(modules.len(), 5);
+
+    let PageModule::AlbumHeader(header) = modules[0] else {
+        panic!("Expected an album header, got {:?}", modules[0]);
+    };
+    assert_eq!(header.album.title, page.title);
+    assert_eq!(header.review.as_ref().unwrap().text_segments().len(), 2);
+
+    let PageModule::AlbumItems(items) = modules[1] else {
+        panic!("Expected album items, got {:?}", modules[1]);
+    };
+    assert!(matches!(items.paged_list.items[0], AlbumItem::Track(_)));
+
+    let PageModule::AlbumList(albums) = modules[2] else {
+        panic!("Expected an album list, got {:?}", modules[2]);
+    };
+    assert_eq!(albums.title, "More Albums");
+    assert_eq!(albums.paged_list.total, 25);
+    assert_eq!(
+        albums.show_more.as_ref().unwrap().api_path,
+        "pages/data/abc"
+    );
+
+    assert!(matches!(modules[3], PageModule::ArtistList(_)));
+    assert_eq!(modules[3].title(), "Related Artists");
+}
+
+#[test]
+fn test_unknown_modules_fall_back() {
+    let page = example_album_page();
+    let modules: Vec<_> = page.modules().collect();
+
+    let PageModule::Other(social) = modules[4] else {
+        panic!("Expected a generic module, got {:?}", modules[4]);
+    };
+    assert_eq!(social.module_type, "SOCIAL");
+    assert_eq!(social.title, "Follow");
+}
+
+#[test]
+fn test_malformed_known_module_is_an_error() {
+    let broken = serde_json::json!({
+        "id": "b",
+        "type": "TRACK_LIST",
+        "title": "Broken",
+        "pagedList": {"items": "nope"}
+    });
+
+    let err = serde_json::from_value::<PageModule>(broken).unwrap_err();
+    assert!(err.to_string().contains("invalid TRACK_LIST module"));
+
+    let page = serde_json::json!({
+        "title": "Broken",
+        "rows": [{"modules": [{"type": "ALBUM_LIST", "title": "More Albums"}]}]
+    });
+    assert!(serde_json::from_value::<Page>(page).is_err());
+}
+
+#[test]
+fn test_page_round_trip() {
+    let page = example_album_page();
+    let json = serde_json::to_value(&page).unwrap();
+
+    assert_eq!(json["rows"][2]["modules"][0]["type"], "ALBUM_LIST");
+    assert_eq!(json["rows"][4]["modules"][0]["type"], "SOCIAL");
+    assert_eq!(
+        json["rows"][4]["modules"][0]["socialLinks"],
+        serde_json::json!([])
+    );
+
+    let round_trip: Page = serde_json::from_value(json).unwrap();
+    let modules: Vec<_> = round_trip.modules().collect();
+    assert!(matches!(modules[2], PageModule::AlbumList(_)));
+    let (PageModule::Other(module), PageModule::Other(original)) =
+        (modules[4], page.modules().nth(4).unwrap())
+    else {
+        panic!("Expected generic modules");
+    };
+    assert_eq!(module.module_type, "SOCIAL");
+    assert_eq!(module.raw, original.raw);
+}
+
+// A home page built from the search result fixture, with mixes, promotions
+// and page links
+fn example_home_page() -> Page {
+    let json_str = std::fs::read_to_string("tests/example_search_result.json")
+        .expect("Failed to read example_search_result.json");
+    let search: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+    let playlists = &search["playlists"]["items"];
+
+    serde_json::from_value(serde_json::json!({
+        "id": "home",
+        "title": "Home",
+        "rows": [
+            {"modules": [{
+                "type": "FEATURED_PROMOTIONS",
+                "title": "",
+                "items": [
+                    {
+                        "title": "OK Computer",
+                        "shortHeader": "Anniversary Edition",
+                        "shortSubHeader": "Radiohead",
+                        "text": "",
+                        "imageId": "ab12cd34-ef56-7890-ab12-cd34ef567890",
+                        "type": "ALBUM",
+                        "artifactId": "58990510"
+                    },
+                    {
+                        "title": "Tidal Rising",
+                        "shortHeader": "Playlist",
+                        "shortSubHeader": "",
+                        "imageId": null,
+                        "type": "PLAYLIST",
+                        "artifactId": "0c1b2a3d-4e5f-6789-0abc-def123456789"
+                    },
+                    {
+                        "title": "Tickets",
+                        "type": "EXTURL",
+                        "artifactId": "https://tidal.com/tickets"
+                    }
+                ]
+            }]},
+            {"modules": [{
+                "type": "MIX_LIST",
+                "title": "My Daily Discovery",
+                "pagedList": {
+                    "limit": 10,
+                    "offset": 0,
+                    "totalNumberOfItems": 1,
+                    "items": [{
+                        "id": "0009a57ac9b5a40d812c7f51b0f92f",
+                        "title": "My Mix 1",
+                        "subTitle": "Radiohead, Portishead",
+                        "mixType": "DISCOVERY_MIX",
+                        "images": {}
+                    }]
+                }
+            }]},
+            {"modules": [{
+                "type": "PLAYLIST_LIST",
+                "title": "Popular Playlists",
+                "pagedList": {
+                    "limit": 10,
+                    "offset": 0,
+                    "totalNumberOfItems": playlists.as_array().unwrap().len(),
+                    "items": playlists
+                }
+            }]},
+            {"modules": [{
+                "type": "PAGE_LINKS_CLOUD",
+                "title": "Genres",
+                "pagedList": {
+                    "limit": 10,
+                    "offset": 0,
+                    "totalNumberOfItems": 2,
+                    "items": [
+                        {"title": "Hip-Hop", "icon": "", "apiPath": "pages/genre_hip_hop", "imageId": null},
+                        {"title": "Relax", "icon": "", "apiPath": "pages/mood_relax", "imageId": null}
+                    ]
+                }
+            }]}
+        ]
+    }))
+    .expect("Failed to deserialize home page")
+}
+
+#[test]
+fn test_deserialize_home_page() {
+    let page = example_home_page();
+    let modules: Vec<_> = page.modules().collect();
+    assert_eq!(modules.len(), 4);
+
+    let PageModule::FeaturedPromotions(promotions) = modules[0] else {
+        panic!("Expected featured promotions, got {:?}", modules[0]);
+    };
+    let items = &promotions.items;
+    assert_eq!(items.len(), 3);
+    assert_eq!(
+        items[0].target(),
+        Some(ResourceId::Album(AlbumId(58990510)))
+    );
+    assert_eq!(
+        items[0].image_url(320, 480).as_deref(),
+        Some("https://resources.tidal.com/images/ab12cd34/ef56/7890/ab12/cd34ef567890/320x480.jpg")
+    );
+    assert_eq!(
+        items[1].target(),
+        Some(ResourceId::Playlist(PlaylistId::from(
+            "0c1b2a3d-4e5f-6789-0abc-def123456789"
+        )))
+    );
+    assert_eq!(items[2].target(), None);
+
+    let PageModule::MixList(mixes) = modules[1] else {
+        panic!("Expected a mix list, got {:?}", modules[1]);
+    };
+    assert_eq!(mixes.paged_list.items[0].title, "My Mix 1");
+
+    let PageModule::PlaylistList(playlists) = modules[2] else {
+        panic!("Expected a playlist list, got {:?}", modules[2]);
+    };
+    assert_eq!(playlists.paged_list.items.len(), 30);
+
+    let PageModule::PageLinks(links) = modules[3] else {
+        panic!("Expected page links, got {:?}", modules[3]);
+    };
+    assert_eq!(links.title, "Genres");
+    assert_eq!(links.paged_list.items[1].api_path, "pages/mood_relax");
+}
@@ -0,0 +1,72 @@
+//! Tests for parsing Tidal links and generating share URLs.
+
+use tidalrs::{AlbumId, ArtistId, PlaylistId, ResourceId, TrackId, VideoId};
+
+#[test]
+fn test_parse_browse_url() {
+    let id = ResourceId::from_url("https://tidal.com/browse/track/123").unwrap();
+    assert_eq!(id, ResourceId::Track(TrackId(123)));
+}
+
+#[test]
+fn test_parse_listen_url() {
+    let id = ResourceId::from_url("https://listen.tidal.com/album/456").unwrap();
+    assert_eq!(id, ResourceId::Album(AlbumId(456)));
+}
+
+#[test]
+fn test_parse_model_url() {
+    // The format used in the `url` field of models
+    let id = ResourceId::from_url("http://www.tidal.com/artist/789").unwrap();
+    assert_eq!(id, ResourceId::Artist(ArtistId(789)));
+}
+
+#[test]
+fn test_parse_app_url() {
+    let id = ResourceId::from_url("tidal://playlist/12345678-1234-1234-1234-123456789abc").unwrap();
+    assert_eq!(
+        id,
+        ResourceId::Playlist(PlaylistId(
+            "12345678-1234-1234-1234-123456789abc".to_string()
+        ))
+    );
+}
+
+#[test]
+fn test_parse_url_with_query_and_suffix() {
+    let id = ResourceId::from_url("https://tidal.com/browse/video/42/u?utm_source=share").unwrap();
+    assert_eq!(id, ResourceId::Video(VideoId(42)));
+}
+
+#[test]
+fn test_parse_nested_url_prefers_last_resource() {
+    let id = ResourceId::from_url("https://tidal.com/browse/album/456/track/123").unwrap();
+    assert_eq!(id, ResourceId::Track(TrackId(123)));
+}
+
+#[test]
+fn test_parse_invalid_urls() {
+    assert!(ResourceId::from_url("not a url").is_err());
+    assert!(ResourceId::from_url("https://example.com/track/123").is_err());
+    assert!(ResourceId::from_url("https://tidal.com/browse/track/abc").is_err());
+    assert!(ResourceId::from_url("https://tidal.com/browse").is_err());
+}
+
+#[test]
+fn test_share_url_round_trip() {
+    let ids = [
+        ResourceId::Track(TrackId(123)),
+        ResourceId::Album(AlbumId(456)),
+        ResourceId::Artist(ArtistId(789)),
+        ResourceId::Video(VideoId(42)),
+        ResourceId::Playlist(PlaylistId(
+            "12345678-1234-1234-1234-123456789abc".to_string(),
+        )),
+    ];
+
+    for id in ids {
+        let url = id.share_url();
+        assert!(url.starts_with("https://tidal.com/browse/"));
+        assert_eq!(ResourceId::from_url(&url).unwrap(), id);
+    }
+}
@@ -0,0 +1,86 @@
+//! Tests for the user profile models.
+//!
+//! This module tests deserialization of public user profiles, cursor-paginated
+//! follow lists, and user profiles in search results.
+
+use tidalrs::{CursorList, Resource, ResourceId, SearchResults, UserId, UserProfile};
+
+fn example_profile() -> serde_json::Value {
+    serde_json::json!({
+        "userId": 192837465,
+        "name": "vinyl_collector",
+        "picture": "3f2a1b4c-5d6e-4f70-8192-a3b4c5d6e7f8",
+        "color": ["#8f5a2b"],
+        "numberOfFollowers": 12,
+        "numberOfFollows": 34
+    })
+}
+
+#[test]
+fn test_deserialize_user_profile() {
+    let profile: UserProfile = serde_json::from_value(example_profile()).unwrap();
+
+    assert_eq!(profile.user_id, UserId(192837465));
+    assert_eq!(profile.name, "vinyl_collector");
+    assert_eq!(profile.number_of_followers, Some(12));
+    assert_eq!(profile.im_following, None);
+    assert_eq!(
+        profile.picture_url(320, 320).as_deref(),
+        Some("https://resources.tidal.com/images/3f2a1b4c/5d6e/4f70/8192/a3b4c5d6e7f8/320x320.jpg")
+    );
+    assert_eq!(
+        profile.share_url(),
+        "https://tidal.com/browse/profile/192837465"
+    );
+
+    let resource = Resource::UserProfiles(profile);
+    assert_eq!(resource.id(), ResourceId::UserProfile(UserId(192837465)));
+}
+
+#[test]
+fn test_deserialize_follow_list() {
+    let followers: CursorList<UserProfile> = serde_json::from_value(serde_json::json!({
+        "items": [
+            {"id": 1, "name": "first", "picture": "https://example.com/first.jpg", "imFollowing": true},
+            {"userId": 2, "name": "second", "picture": null}
+        ],
+        "cursor": "bmV4dA=="
+    }))
+    .unwrap();
+
+    assert_eq!(followers.items.len(), 2);
+    assert_eq!(followers.items[0].user_id, UserId(1));
+    assert_eq!(followers.items[0].im_following, Some(true));
+    assert_eq!(
+        followers.items[0].picture_url(100, 100).as_deref(),
+        Some("https://example.com/first.jpg")
+    );
+    assert!(followers.items[1].picture_url(100, 100).is_none());
+    assert_eq!(followers.cursor.as_deref(), Some("bmV4dA=="));
+
+    let last_page: CursorList<UserProfile> =
+        serde_json::from_value(serde_json::json!({ "items": [] })).unwrap();
+    assert!(last_page.cursor.is_none());
+}
+
+#[test]
+fn test_user_profiles_in_search_results() {
+    let search_results: SearchResults = serde_json::from_value(serde_json::json!({
+        "userProfiles": {
+            "limit": 10,
+            "offset": 0,
+            "totalNumberOfItems": 1,
+            "items": [example_profile()]
+        },
+        "topHits": [
+            {"type": "USER_PROFILES", "value": example_profile()}
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(search_results.user_profiles.items.len(), 1);
+    assert_eq!(
+        search_results.top_hits[0].id(),
+        ResourceId::UserProfile(UserId(192837465))
+    );
+}
@@ -0,0 +1,122 @@
+//! Tests for the video models.
+//!
+//! This module tests deserialization of videos, album items that mix tracks
+//! and videos, and decoding of HLS video manifests.
+
+use base64::Engine;
+use tidalrs::{AlbumItem, List, Resource, ResourceId, Video, VideoId, VideoPlaybackInfo};
+
+fn example_video() -> serde_json::Value {
+    serde_json::json!({
+        "id": 75623239,
+        "title": "Paranoid Android",
+        "volumeNumber": 1,
+        "trackNumber": 3,
+        "releaseDate": "1997-06-16T00:00:00.000+0000",
+        "imagePath": null,
+        "imageId": "6b4e5a3f-2c1d-4b7e-9f0a-8d2c1e3b4a5f",
+        "vibrantColor": "#f2d8a0",
+        "duration": 387,
+        "quality": "MP4_1080P",
+        "streamReady": true,
+        "adSupportedStreamReady": true,
+        "allowStreaming": true,
+        "explicit": false,
+        "popularity": 42,
+        "type": "Music Video",
+        "artists": [
+            {"id": 64518, "name": "Radiohead", "type": "MAIN", "picture": null}
+        ],
+        "album": null
+    })
+}
+
+#[test]
+fn test_deserialize_video() {
+    let video: Video = serde_json::from_value(example_video()).unwrap();
+
+    assert_eq!(video.id, VideoId(75623239));
+    assert_eq!(video.title, "Paranoid Android");
+    assert_eq!(video.duration, 387);
+    assert_eq!(video.video_type.as_deref(), Some("Music Video"));
+    assert_eq!(video.artists[0].name, "Radiohead");
+    assert!(video.album.is_none());
+
+    assert_eq!(
+        video.image_url(480, 320).as_deref(),
+        Some("https://resources.tidal.com/images/6b4e5a3f/2c1d/4b7e/9f0a/8d2c1e3b4a5f/480x320.jpg")
+    );
+    assert!(video.thumbnail_url().unwrap().ends_with("/160x107.jpg"));
+    assert_eq!(video.share_url(), "https://tidal.com/browse/video/75623239");
+
+    let resource = Resource::Videos(video);
+    assert_eq!(resource.id(), ResourceId::Video(VideoId(75623239)));
+}
+
+#[test]
+fn test_deserialize_album_items_with_videos() {
+    let json_str = std::fs::read_to_string("tests/example_search_result.json")
+        .expect("Failed to read example_search_result.json");
+    let search_results: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+    let track = search_results["tracks"]["items"][0].clone();
+
+    let items = serde_json::json!({
+        "limit": 10,
+        "offset": 0,
+        "totalNumberOfItems": 2,
+        "items": [
+            {"item": track, "type": "track"},
+            {"item": example_video(), "type": "video"}
+        ]
+    });
+
+    let items: List<AlbumItem> = serde_json::from_value(items).unwrap();
+    assert_eq!(items.items.len(), 2);
+    assert!(matches!(items.items[0], AlbumItem::Track(_)));
+    assert!(matches!(&items.items[1], AlbumItem::Video(video) if video.id == VideoId(75623239)));
+}
+
+#[test]
+fn test_decode_hls_manifest() {
+    let manifest = serde_json::json!({
+        "mimeType": "application/vnd.apple.mpegurl",
+        "urls": ["https://example.com/video/master.m3u8"]
+    });
+    let encoded = base64::engine::general_purpose::STANDARD.encode(manifest.to_string());
+
+    let playback_info: VideoPlaybackInfo = serde_json::from_value(serde_json::json!({
+        "videoId": 75623239,
+        "streamType": "ON_DEMAND",
+        "assetPresentation": "FULL",
+        "videoQuality": "HIGH",
+        "manifestMimeType": "application/vnd.tidal.emu",
+        "manifestHash": "abc123",
+        "manifest": encoded
+    }))
+    .unwrap();
+
+    let manifest = playback_info.hls_manifest().unwrap();
+    assert_eq!(manifest.mime_type, "application/vnd.apple.mpegurl");
+    assert_eq!(
+        manifest.primary_url(),
+        Some("https://example.com/video/master.m3u8")
+    );
+}
+
+#[test]
+fn test_invalid_hls_manifest_is_an_error() {
+    let playback_info: VideoPlaybackInfo = serde_json::from_value(serde_json::json!({
+        "videoId": 75623239,
+        "streamType": "ON_DEMAND",
+        "assetPresentation": "FULL",
+        "videoQuality": "HIGH",
+        "manifestMimeType": "application/vnd.tidal.emu",
+        "manifest": "not base64!"
+    }))
+    .unwrap();
+
+    assert!(matches!(
+        playback_info.hls_manifest(),
+        Err(tidalrs::Error::Base64Decode(_))
+    ));
+}